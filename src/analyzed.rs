@@ -1,10 +1,11 @@
-use std::ffi::CStr;
+use std::{ffi::CStr, sync::OnceLock};
 
-use widestring::{U16CStr, U16String};
+use widestring::{U16CStr, U16Str, U16String};
 
-use crate::{bindings::*, kiwi_error, KiwiRc, POSTag};
+use crate::{bindings::*, check_float, kiwi_error, Error, Kiwi, KiwiRc, POSTag, Result};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token {
     /// 시작 위치 (UTF16 문자 기준)
     pub chr_position: usize,
@@ -16,6 +17,12 @@ pub struct Token {
     pub line_number: usize,
     /// 길이 (UTF16 문자 기준)
     pub length: usize,
+    /// 시작 위치 (UTF-8 바이트 기준)
+    ///
+    /// 분석한 원본 텍스트를 UTF-8로 인코딩했을 때의 위치이므로, `&text[byte_begin..byte_end]`처럼 원본 문자열을 바로 자를 수 있습니다.
+    pub byte_begin: usize,
+    /// 끝 위치 (UTF-8 바이트 기준)
+    pub byte_end: usize,
     /// 품사 태그
     pub tag: POSTag,
     /// 해당 형태소의 언어모델 점수
@@ -35,6 +42,43 @@ pub struct Token {
     pub sense_id: u8,
     /// 유니코드 영역에 기반한 문자 타입
     pub script: u8,
+
+    /// 형태소 id
+    ///
+    /// 같은 [Kiwi](crate::Kiwi) 내에서 형태소마다 고유하며, [Kiwi::morpheme](crate::Kiwi::morpheme)으로 형태소의 정보를 조회할 수 있습니다.
+    pub morph_id: u32,
+}
+
+/// [Analyzed]를 변환한 분석 결과
+///
+/// 네이티브 핸들을 가지지 않는 순수한 Rust 값이므로, 캐시에 저장하거나 스레드 간에 자유롭게 주고받을 수 있습니다.
+///
+/// [Analyzed::to_result] 참고
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnalysisResult {
+    /// 분석 결과 후보 목록 (확률 점수가 높은 순)
+    pub candidates: Vec<Candidate>,
+}
+
+/// 분석 결과 후보 하나
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candidate {
+    /// 확률 점수
+    pub prob: f32,
+    /// 형태소 목록
+    pub tokens: Vec<OwnedToken>,
+}
+
+/// 형태(UTF-8)와 [토큰 정보](Token)를 묶은 형태소
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedToken {
+    /// 형태 (UTF-8)
+    pub form: String,
+    /// 토큰 정보
+    pub token: Token,
 }
 
 /// [Kiwi::analyze] 또는 [Kiwi::analyze_w]의 반환 값
@@ -44,6 +88,10 @@ pub struct Token {
 #[derive(Clone)]
 pub struct Analyzed {
     pub(crate) handle: KiwiRc<kiwi_res_h>,
+    /// 형태소 id를 조회하는데 사용됨
+    kiwi: Kiwi,
+    /// 분석한 원본 텍스트
+    source: KiwiRc<Source>,
     size: usize,
     /// words\[n\] = word_num
     words: Box<[usize]>,
@@ -54,48 +102,123 @@ unsafe impl Send for Analyzed {}
 // unsafe impl Sync for Analyzed {}
 
 #[inline]
-fn size(handle: kiwi_res_h) -> usize {
+fn size(handle: kiwi_res_h) -> Result<usize> {
     unsafe {
         let size = kiwi_res_size(handle);
 
         if size < 0 {
-            let err = kiwi_error();
-            panic!("analyzed.size() -> {:?}", err);
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
         }
 
-        size as usize
+        Ok(size as usize)
     }
 }
 
 #[inline]
-fn word_num(handle: kiwi_res_h, index: usize) -> usize {
+fn word_num(handle: kiwi_res_h, index: usize) -> Result<usize> {
     unsafe {
         let word_num = kiwi_res_word_num(handle, index as i32);
 
         if word_num < 0 {
-            let err = kiwi_error();
-            panic!("analyzed.word_num({}) -> {:?}", index, err);
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
         }
 
-        word_num as usize
+        Ok(word_num as usize)
     }
 }
 
-impl Analyzed {
-    pub(crate) fn new(handle: kiwi_res_h) -> Self {
-        let size = size(handle);
-        let mut words = Vec::with_capacity(size);
+/// words\[n\] = word_num
+fn words(handle: kiwi_res_h) -> Result<Vec<usize>> {
+    let size = size(handle)?;
+
+    (0..size).map(|i| word_num(handle, i)).collect()
+}
+
+/// UTF-16 위치를 UTF-8 바이트 위치로 변환하는 테이블을 생성합니다.
+///
+/// 테이블의 길이는 UTF-16 길이 + 1 이며, 마지막 값은 UTF-8 바이트 길이입니다.
+fn utf16_to_byte_offsets(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+
+    for (i, c) in text.char_indices() {
+        for _ in 0..c.len_utf16() {
+            offsets.push(i);
+        }
+    }
+
+    offsets.push(text.len());
+
+    offsets
+}
+
+/// 분석한 원본 텍스트
+pub(crate) enum SourceText {
+    Utf8(Box<str>),
+    Utf16(U16String),
+}
+
+impl From<&str> for SourceText {
+    fn from(text: &str) -> Self {
+        Self::Utf8(text.into())
+    }
+}
+
+impl From<&U16Str> for SourceText {
+    fn from(text: &U16Str) -> Self {
+        Self::Utf16(text.to_ustring())
+    }
+}
+
+/// 원본 텍스트와, 토큰의 UTF-8 위치를 구할 때 처음 생성하는 변환 결과들
+struct Source {
+    text: SourceText,
+    /// UTF-16으로 분석한 경우 UTF-8로 변환한 텍스트
+    utf8: OnceLock<String>,
+    /// offsets\[UTF-16 위치\] = UTF-8 바이트 위치
+    offsets: OnceLock<Box<[usize]>>,
+}
 
-        for i in 0..size {
-            words.push(word_num(handle, i));
+impl Source {
+    fn text(&self) -> &str {
+        match &self.text {
+            SourceText::Utf8(text) => text,
+            SourceText::Utf16(text) => self.utf8.get_or_init(|| text.to_string_lossy()),
         }
+    }
 
-        Self {
+    fn offsets(&self) -> &[usize] {
+        self.offsets
+            .get_or_init(|| utf16_to_byte_offsets(self.text()).into_boxed_slice())
+    }
+}
+
+impl Analyzed {
+    /// # Parameters
+    /// * `text` - 분석한 원본 텍스트
+    pub(crate) fn new(handle: kiwi_res_h, kiwi: &Kiwi, text: SourceText) -> Result<Self> {
+        let words = match words(handle) {
+            Ok(words) => words,
+            Err(err) => {
+                // 핸들을 [Analyzed]로 감싸기 전에 실패한 경우 직접 해제함
+                unsafe { kiwi_res_close(handle) };
+                return Err(err);
+            }
+        };
+
+        Ok(Self {
             #[allow(clippy::arc_with_non_send_sync)]
             handle: KiwiRc::new(handle),
-            size,
+            kiwi: kiwi.clone(),
+            source: KiwiRc::new(Source {
+                text,
+                utf8: OnceLock::new(),
+                offsets: OnceLock::new(),
+            }),
+            size: words.len(),
             words: words.into_boxed_slice(),
-        }
+        })
     }
 
     #[inline]
@@ -113,19 +236,17 @@ impl Analyzed {
     }
 
     /// index번째 분석 결과의 확률 점수를 반환합니다.
-    pub fn prob(&self, index: usize) -> Option<f32> {
-        self.check_index(index, None)?;
+    pub fn prob(&self, index: usize) -> Result<Option<f32>> {
+        let Some(()) = self.check_index(index, None) else {
+            return Ok(None);
+        };
 
-        unsafe {
-            let prob = kiwi_res_prob(*self.handle, index as i32);
-
-            if prob == 0.0 {
-                let err = kiwi_error();
-                panic!("analyzed.prob({}) -> {:?}", index, err);
-            }
+        self.prob_unchecked(index).map(Some)
+    }
 
-            Some(prob)
-        }
+    #[inline]
+    fn prob_unchecked(&self, index: usize) -> Result<f32> {
+        check_float(|| unsafe { kiwi_res_prob(*self.handle, index as i32) })
     }
 
     /// index번째 분석 결과 내에 포함된 형태소의 개수를 반환합니다.
@@ -138,19 +259,20 @@ impl Analyzed {
     ///
     /// # Return
     /// [Token] 참고
-    pub fn token(&self, index: usize, word_num: usize) -> Option<Token> {
-        self.check_index(index, word_num)?;
-        Some(self.token_unchecked(index, word_num))
+    pub fn token(&self, index: usize, word_num: usize) -> Result<Option<Token>> {
+        self.check_index(index, word_num)
+            .map(|_| self.token_unchecked(index, word_num))
+            .transpose()
     }
 
     #[inline]
-    fn token_unchecked(&self, index: usize, word_num: usize) -> Token {
+    fn token_unchecked(&self, index: usize, word_num: usize) -> Result<Token> {
         unsafe {
             let token = kiwi_res_token_info(*self.handle, index as i32, word_num as i32);
 
             if token.is_null() {
-                let err = kiwi_error();
-                panic!("analyzed.token({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let kiwi_token_info_t {
@@ -168,12 +290,16 @@ impl Analyzed {
                 sub_sent_position,
             } = *token as kiwi_token_info_t;
 
-            Token {
+            let (byte_begin, byte_end) = self.byte_span(chr_position as usize, length as usize);
+
+            Ok(Token {
                 chr_position: chr_position as usize,
                 word_position: word_position as usize,
                 sent_position: sent_position as usize,
                 line_number: line_number as usize,
                 length: length as usize,
+                byte_begin,
+                byte_end,
                 tag: POSTag(tag),
                 sense_id: u.sense_id,
                 script: u.script,
@@ -182,7 +308,64 @@ impl Analyzed {
                 typo_form_id,
                 paired_token,
                 sub_sent_position: sub_sent_position as usize,
+                morph_id: self.morpheme_id_unchecked(index, word_num)?,
+            })
+        }
+    }
+
+    #[inline]
+    fn byte_span(&self, chr_position: usize, length: usize) -> (usize, usize) {
+        let offsets = self.source.offsets();
+        let last = offsets.len() - 1;
+
+        let begin = offsets[chr_position.min(last)];
+        let end = offsets[(chr_position + length).min(last)];
+
+        (begin, end)
+    }
+
+    /// 분석한 원본 텍스트를 반환합니다.
+    ///
+    /// [Kiwi::analyze_w](crate::Kiwi::analyze_w)로 분석한 경우 처음 호출할 때 UTF-8로 변환한 텍스트를 반환합니다.
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.source.text()
+    }
+
+    /// index번째 분석 결과의 word_num번째 형태소가 원본 텍스트에서 차지하는 부분을 반환합니다.
+    ///
+    /// [Analyzed::form]은 분석된 형태소의 형태를 반환하는 반면, 이 메서드는 원본 텍스트의 해당 구간을 그대로 반환합니다.
+    /// 예) `했다`의 `하/VV`는 형태가 `하`이지만, 원본 텍스트에서는 `했`에 해당합니다.
+    pub fn surface(&self, index: usize, word_num: usize) -> Result<Option<&str>> {
+        let Some(token) = self.token(index, word_num)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(&self.text()[token.byte_begin..token.byte_end]))
+    }
+
+    /// index번째 분석 결과의 word_num번째 형태소의 형태소 id를 반환합니다.
+    ///
+    /// [Kiwi::morpheme](crate::Kiwi::morpheme)으로 형태소의 정보를 조회할 수 있습니다.
+    pub fn morpheme_id(&self, index: usize, word_num: usize) -> Result<Option<u32>> {
+        self.check_index(index, word_num)
+            .map(|_| self.morpheme_id_unchecked(index, word_num))
+            .transpose()
+    }
+
+    #[inline]
+    fn morpheme_id_unchecked(&self, index: usize, word_num: usize) -> Result<u32> {
+        unsafe {
+            let kiwi_handle = self.kiwi.handle.read();
+            let morph_id =
+                kiwi_res_morpheme_id(*self.handle, index as i32, word_num as i32, *kiwi_handle);
+
+            if morph_id < 0 {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
+
+            Ok(morph_id as u32)
         }
     }
 
@@ -190,24 +373,25 @@ impl Analyzed {
     ///
     /// # Return
     /// UTF-8 인코딩된 문자열
-    pub fn form(&self, index: usize, word_num: usize) -> Option<String> {
-        self.check_index(index, word_num)?;
-        Some(self.form_unchecked(index, word_num))
+    pub fn form(&self, index: usize, word_num: usize) -> Result<Option<String>> {
+        self.check_index(index, word_num)
+            .map(|_| self.form_unchecked(index, word_num))
+            .transpose()
     }
 
     #[inline]
-    fn form_unchecked(&self, index: usize, word_num: usize) -> String {
+    fn form_unchecked(&self, index: usize, word_num: usize) -> Result<String> {
         unsafe {
             let form = kiwi_res_form(*self.handle, index as i32, word_num as i32);
 
             if form.is_null() {
-                let err = kiwi_error();
-                panic!("analyzed.form({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let form = CStr::from_ptr(form);
 
-            form.to_owned().into_string().unwrap()
+            Ok(form.to_string_lossy().into_owned())
         }
     }
 
@@ -215,24 +399,25 @@ impl Analyzed {
     ///
     /// # Return
     /// UTF-16 인코딩된 문자열
-    pub fn form_w(&self, index: usize, word_num: usize) -> Option<U16String> {
-        self.check_index(index, word_num)?;
-        Some(self.form_w_unchecked(index, word_num))
+    pub fn form_w(&self, index: usize, word_num: usize) -> Result<Option<U16String>> {
+        self.check_index(index, word_num)
+            .map(|_| self.form_w_unchecked(index, word_num))
+            .transpose()
     }
 
     #[inline]
-    fn form_w_unchecked(&self, index: usize, word_num: usize) -> U16String {
+    fn form_w_unchecked(&self, index: usize, word_num: usize) -> Result<U16String> {
         unsafe {
             let form = kiwi_res_form_w(*self.handle, index as i32, word_num as i32);
 
             if form.is_null() {
-                let err = kiwi_error();
-                panic!("analyzed.form_w({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let form = U16CStr::from_ptr_str(form);
 
-            form.to_owned().into_ustring()
+            Ok(form.to_owned().into_ustring())
         }
     }
 
@@ -240,20 +425,22 @@ impl Analyzed {
     ///
     /// # Return
     /// UTF-8 인코딩된 문자열
-    pub fn tag(&self, index: usize, word_num: usize) -> Option<String> {
-        self.check_index(index, word_num)?;
+    pub fn tag(&self, index: usize, word_num: usize) -> Result<Option<String>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
         unsafe {
             let tag = kiwi_res_tag(*self.handle, index as i32, word_num as i32);
 
             if tag.is_null() {
-                let err = kiwi_error();
-                panic!("analyzed.tag({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let tag = CStr::from_ptr(tag);
 
-            Some(tag.to_owned().into_string().unwrap())
+            Ok(Some(tag.to_string_lossy().into_owned()))
         }
     }
 
@@ -261,114 +448,114 @@ impl Analyzed {
     ///
     /// # Return
     /// UTF-16 인코딩된 문자열
-    pub fn tag_w(&self, index: usize, word_num: usize) -> Option<U16String> {
-        self.check_index(index, word_num)?;
+    pub fn tag_w(&self, index: usize, word_num: usize) -> Result<Option<U16String>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
         unsafe {
             let tag = kiwi_res_tag_w(*self.handle, index as i32, word_num as i32);
 
             if tag.is_null() {
-                let err = kiwi_error();
-                panic!("analyzed.tag_w({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let tag = U16CStr::from_ptr_str(tag);
 
-            Some(tag.to_owned().into_ustring())
+            Ok(Some(tag.to_owned().into_ustring()))
         }
     }
 
     /// index번째 분석 결과의 word_num번째 형태소의 길이(utf-16 문자열 기준)를 반환합니다.
-    pub fn length(&self, index: usize, word_num: usize) -> Option<usize> {
-        self.check_index(index, word_num)?;
+    pub fn length(&self, index: usize, word_num: usize) -> Result<Option<usize>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
         unsafe {
             let length = kiwi_res_length(*self.handle, index as i32, word_num as i32);
 
             if length < 0 {
-                let err = kiwi_error();
-                panic!("analyzed.length({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
-            Some(length as usize)
+            Ok(Some(length as usize))
         }
     }
 
     /// index번째 분석 결과의 word_num번째 형태소의 문장 내 어절 번호를 반환합니다.
-    pub fn word_position(&self, index: usize, word_num: usize) -> Option<usize> {
-        self.check_index(index, word_num)?;
+    pub fn word_position(&self, index: usize, word_num: usize) -> Result<Option<usize>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
         unsafe {
             let word_position = kiwi_res_word_position(*self.handle, index as i32, word_num as i32);
 
             if word_position < 0 {
-                let err = kiwi_error();
-                panic!(
-                    "analyzed.word_position({}, {}) -> {:?}",
-                    index, word_num, err
-                );
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
-            Some(word_position as usize)
+            Ok(Some(word_position as usize))
         }
     }
 
     /// index번째 분석 결과의 word_num번째 형태소의 문장 번호를 반환합니다.
-    pub fn sent_position(&self, index: usize, word_num: usize) -> Option<usize> {
-        self.check_index(index, word_num)?;
+    pub fn sent_position(&self, index: usize, word_num: usize) -> Result<Option<usize>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
         unsafe {
             let sent_position = kiwi_res_sent_position(*self.handle, index as i32, word_num as i32);
 
             if sent_position < 0 {
-                let err = kiwi_error();
-                panic!(
-                    "analyzed.sent_position({}, {}) -> {:?}",
-                    index, word_num, err
-                );
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
-            Some(sent_position as usize)
+            Ok(Some(sent_position as usize))
         }
     }
 
     /// index번째 분석 결과의 word_num번째 형태소의 언어 모델 점수를 반환합니다.
-    pub fn score(&self, index: usize, word_num: usize) -> Option<f32> {
-        self.check_index(index, word_num)?;
+    pub fn score(&self, index: usize, word_num: usize) -> Result<Option<f32>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
-        unsafe {
-            let score = kiwi_res_score(*self.handle, index as i32, word_num as i32);
-
-            if score == 0.0 {
-                let err = kiwi_error();
-                panic!("analyzed.score({}, {}) -> {:?}", index, word_num, err);
-            }
+        let score =
+            check_float(|| unsafe { kiwi_res_score(*self.handle, index as i32, word_num as i32) })?;
 
-            Some(score)
-        }
+        Ok(Some(score))
     }
 
     /// index번째 분석 결과의 word_num번째 형태소의 오타 교정 비용을 반환합니다.
     ///
     /// # Return
     /// `0`은 오타 교정이 발생하지 않았음을 의미함
-    pub fn typo_cost(&self, index: usize, word_num: usize) -> Option<f32> {
-        self.check_index(index, word_num)?;
+    pub fn typo_cost(&self, index: usize, word_num: usize) -> Result<Option<f32>> {
+        let Some(()) = self.check_index(index, word_num) else {
+            return Ok(None);
+        };
 
         unsafe {
             let typo_cost = kiwi_res_typo_cost(*self.handle, index as i32, word_num as i32);
 
             if typo_cost < 0.0 {
-                let err = kiwi_error();
-                panic!("analyzed.typo_cost({}, {}) -> {:?}", index, word_num, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
-            Some(typo_cost)
+            Ok(Some(typo_cost))
         }
     }
 
     /// 모든 분석 결과를 [형태소(UTF-8)](Analyzed::form)와 [토큰 정보](Analyzed::token_info)를 묶어 리스트로 반환합니다.
-    pub fn to_vec(&self) -> Vec<(String, Token)> {
+    pub fn to_vec(&self) -> Result<Vec<(String, Token)>> {
         let res_size = self.size();
         let mut tokens = Vec::with_capacity(res_size);
 
@@ -379,11 +566,11 @@ impl Analyzed {
             // token_result = (vector<token_info>, score: float)
             // token_info
 
-            let word_num = self.word_num(i).unwrap();
+            let word_num = self.words[i];
 
             for j in 0..word_num {
-                let form = self.form_unchecked(i, j);
-                let token = self.token_unchecked(i, j);
+                let form = self.form_unchecked(i, j)?;
+                let token = self.token_unchecked(i, j)?;
 
                 // println!("{} {}", form, token.tag);
 
@@ -391,20 +578,20 @@ impl Analyzed {
             }
         }
 
-        tokens
+        Ok(tokens)
     }
 
     /// 모든 분석 결과를 [형태소(UTF-16)](Analyzed::form_w)와 [토큰 정보](Analyzed::token_info)를 묶어 리스트로 반환합니다.
-    pub fn to_vec_w(&self) -> Vec<(U16String, Token)> {
+    pub fn to_vec_w(&self) -> Result<Vec<(U16String, Token)>> {
         let res_size = self.size();
         let mut tokens = Vec::with_capacity(res_size);
 
         for i in 0..res_size {
-            let word_num = self.word_num(i).unwrap();
+            let word_num = self.words[i];
 
             for j in 0..word_num {
-                let form = self.form_w_unchecked(i, j);
-                let token = self.token_unchecked(i, j);
+                let form = self.form_w_unchecked(i, j)?;
+                let token = self.token_unchecked(i, j)?;
 
                 // println!("{:?} {}", form, token.tag);
 
@@ -412,19 +599,19 @@ impl Analyzed {
             }
         }
 
-        tokens
+        Ok(tokens)
     }
 
     /// 모든 분석 결과의 [토큰 정보](Analyzed::token_info)를 리스트로 반환합니다.
-    pub fn to_vec_t(&self) -> Vec<Token> {
+    pub fn to_vec_t(&self) -> Result<Vec<Token>> {
         let res_size = self.size();
         let mut tokens = Vec::with_capacity(res_size);
 
         for i in 0..res_size {
-            let word_num = self.word_num(i).unwrap();
+            let word_num = self.words[i];
 
             for j in 0..word_num {
-                let token = self.token_unchecked(i, j);
+                let token = self.token_unchecked(i, j)?;
 
                 // println!("{} {}", form, token.tag);
 
@@ -432,7 +619,31 @@ impl Analyzed {
             }
         }
 
-        tokens
+        Ok(tokens)
+    }
+
+    /// 모든 분석 결과를 네이티브 핸들과 분리된 [AnalysisResult]로 변환합니다.
+    pub fn to_result(&self) -> Result<AnalysisResult> {
+        let mut candidates = Vec::with_capacity(self.size);
+
+        for i in 0..self.size {
+            let word_num = self.words[i];
+            let mut tokens = Vec::with_capacity(word_num);
+
+            for j in 0..word_num {
+                tokens.push(OwnedToken {
+                    form: self.form_unchecked(i, j)?,
+                    token: self.token_unchecked(i, j)?,
+                });
+            }
+
+            candidates.push(Candidate {
+                prob: self.prob_unchecked(i)?,
+                tokens,
+            });
+        }
+
+        Ok(AnalysisResult { candidates })
     }
 
     /// 모든 분석 결과를 [형태소(UTF-8)](Analyzed::form)와 [토큰 정보](Analyzed::token_info)를 묶은 이터레이터 구조체를 반환합니다.
@@ -451,6 +662,22 @@ impl Analyzed {
     }
 }
 
+impl TryFrom<&Analyzed> for AnalysisResult {
+    type Error = Error;
+
+    fn try_from(analyzed: &Analyzed) -> Result<Self> {
+        analyzed.to_result()
+    }
+}
+
+impl TryFrom<Analyzed> for AnalysisResult {
+    type Error = Error;
+
+    fn try_from(analyzed: Analyzed) -> Result<Self> {
+        analyzed.to_result()
+    }
+}
+
 impl Drop for Analyzed {
     fn drop(&mut self) {
         if KiwiRc::strong_count(&self.handle) > 1 {
@@ -460,8 +687,9 @@ impl Drop for Analyzed {
         let res = unsafe { kiwi_res_close(*self.handle) };
 
         if res != 0 {
-            let err = kiwi_error();
-            panic!("Analyzed close error: {:?}", err);
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `Analyzed`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `Analyzed`");
@@ -496,7 +724,7 @@ macro_rules! impl_iterator {
             }
 
             impl Iterator for $struct_name<'_> {
-                type Item = $item_ty;
+                type Item = Result<$item_ty>;
 
                 fn next(&mut self) -> Option<Self::Item> {
                     if self.analyzed.check_index(self.i, self.j).is_none() {
@@ -505,8 +733,8 @@ macro_rules! impl_iterator {
                         self.analyzed.check_index(self.i, None)?;
                     }
 
-                    let item = ($(self.analyzed.$item_fn(self.i, self.j),)+);
-                    $(let item = $item_mapper(item);)?
+                    let item = (|| -> Result<_> { Ok(($(self.analyzed.$item_fn(self.i, self.j)?,)+)) })();
+                    $(let item = item.map($item_mapper);)?
 
                     self.j += 1;
 
@@ -0,0 +1,270 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{AnalysisResult, Error, Kiwi, Match, Result};
+
+/// [AsyncKiwi]를 생성할 때 사용하는 옵션 구조체
+///
+/// # Default
+///
+/// 작업 스레드 1개, 대기열 크기 256, 배치 크기 64
+#[derive(Debug, Clone)]
+pub struct AsyncKiwiOptions {
+    workers: usize,
+    queue_size: usize,
+    max_batch_size: usize,
+}
+
+impl AsyncKiwiOptions {
+    /// 분석 작업을 처리할 전용 스레드의 개수를 설정합니다.
+    ///
+    /// 각 스레드는 대기열에서 꺼낸 작업들을 [Kiwi::analyze_many]로 한 번에 분석하므로,
+    /// 실제 분석은 [KiwiBuilder::new](crate::KiwiBuilder::new)에서 설정한 네이티브 스레드 풀에서 병렬로 처리됩니다.
+    pub fn workers(mut self, r: usize) -> Self {
+        self.workers = r.max(1);
+        self
+    }
+
+    /// 분석을 기다리는 작업의 최대 개수를 설정합니다.
+    ///
+    /// 대기열이 가득 차면 [AsyncKiwi::analyze]는 자리가 날 때까지 기다립니다.
+    pub fn queue_size(mut self, r: usize) -> Self {
+        self.queue_size = r.max(1);
+        self
+    }
+
+    /// 한 번에 분석할 작업의 최대 개수를 설정합니다.
+    pub fn max_batch_size(mut self, r: usize) -> Self {
+        self.max_batch_size = r.max(1);
+        self
+    }
+}
+
+impl Default for AsyncKiwiOptions {
+    fn default() -> Self {
+        Self {
+            workers: 1,
+            queue_size: 256,
+            max_batch_size: 64,
+        }
+    }
+}
+
+struct Job {
+    text: String,
+    top_n: i32,
+    match_options: Match,
+    reply: oneshot::Sender<Result<AnalysisResult>>,
+}
+
+/// 비동기 형태소 분석기
+///
+/// 분석 작업을 전용 스레드로 보내 처리하므로, 비동기 런타임의 스레드를 막지 않습니다.
+///
+/// 복제([Clone])하면 같은 대기열과 작업 스레드를 공유하며, 모든 [AsyncKiwi]가 해제되면 작업 스레드도 종료됩니다.
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::{AsyncKiwi, KiwiBuilder, Match};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let kiwi = KiwiBuilder::new(None, Default::default())
+///     .unwrap()
+///     .build(None, None)
+///     .unwrap();
+///
+/// let kiwi = AsyncKiwi::new(kiwi, Default::default());
+///
+/// let analyzed = kiwi.analyze("만둣국 먹고 싶다.", 1, Match::new()).await.unwrap();
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct AsyncKiwi {
+    kiwi: Kiwi,
+    sender: mpsc::Sender<Job>,
+}
+
+impl AsyncKiwi {
+    /// [AsyncKiwi]를 생성하고 작업 스레드를 시작합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `kiwi` - 분석에 사용할 [Kiwi]
+    /// * `options` - [AsyncKiwiOptions] 참고
+    pub fn new(kiwi: Kiwi, options: AsyncKiwiOptions) -> Self {
+        let (sender, receiver) = mpsc::channel(options.queue_size);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for i in 0..options.workers {
+            let kiwi = kiwi.clone();
+            let receiver = receiver.clone();
+            let max_batch_size = options.max_batch_size;
+
+            std::thread::Builder::new()
+                .name(format!("kiwi-worker-{}", i))
+                .spawn(move || worker(kiwi, receiver, max_batch_size))
+                .expect("can't spawn kiwi worker thread");
+        }
+
+        Self { kiwi, sender }
+    }
+
+    /// 분석에 사용하는 [Kiwi]를 반환합니다.
+    #[inline]
+    pub fn kiwi(&self) -> &Kiwi {
+        &self.kiwi
+    }
+
+    /// 텍스트를 분석해 형태소 결과를 반환합니다.
+    ///
+    /// 반환된 future가 완료되기 전에 해제되면, 아직 분석이 시작되지 않은 작업은 분석하지 않고 버립니다.
+    ///
+    /// # Parameters
+    /// * `text` - 분석할 텍스트 (utf-8)
+    /// * `top_n` - 분석 결과 후보를 상위 몇개까지 생성할지 설정합니다.
+    /// * `match_options` - [Match] 참고
+    ///
+    /// # Return
+    /// [AnalysisResult] 참고
+    pub async fn analyze(
+        &self,
+        text: impl Into<String>,
+        top_n: i32,
+        match_options: Match,
+    ) -> Result<AnalysisResult> {
+        let (reply, receiver) = oneshot::channel();
+
+        let job = Job {
+            text: text.into(),
+            top_n,
+            match_options,
+            reply,
+        };
+
+        // 대기열이 가득 찬 경우 자리가 날 때까지 기다림
+        self.sender
+            .send(job)
+            .await
+            .map_err(|_| Error::Disconnected)?;
+
+        receiver.await.map_err(|_| Error::Disconnected)?
+    }
+}
+
+fn worker(kiwi: Kiwi, receiver: Arc<Mutex<mpsc::Receiver<Job>>>, max_batch_size: usize) {
+    loop {
+        let jobs = {
+            let mut receiver = receiver.lock();
+
+            let Some(job) = receiver.blocking_recv() else {
+                break;
+            };
+
+            let mut jobs = vec![job];
+
+            while jobs.len() < max_batch_size {
+                match receiver.try_recv() {
+                    Ok(job) => jobs.push(job),
+                    Err(_) => break,
+                }
+            }
+
+            jobs
+        };
+
+        dispatch(&kiwi, jobs);
+    }
+
+    tracing::trace!("stopped `AsyncKiwi` worker");
+}
+
+/// 같은 옵션을 사용하는 작업끼리 묶습니다.
+///
+/// 결과를 기다리는 쪽이 취소된 작업은 분석하지 않도록 제외합니다.
+fn group_jobs(jobs: Vec<Job>) -> Vec<((i32, Match), Vec<Job>)> {
+    let mut groups: Vec<((i32, Match), Vec<Job>)> = Vec::new();
+
+    for job in jobs.into_iter().filter(|job| !job.reply.is_closed()) {
+        let key = (job.top_n, job.match_options);
+
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(job),
+            None => groups.push((key, vec![job])),
+        }
+    }
+
+    groups
+}
+
+fn dispatch(kiwi: &Kiwi, jobs: Vec<Job>) {
+    // 같은 옵션을 사용하는 작업끼리 묶어서 한 번에 분석함
+    for ((top_n, match_options), jobs) in group_jobs(jobs) {
+        let texts = jobs.iter().map(|job| job.text.as_str());
+
+        match kiwi.analyze_many(texts, top_n, match_options, None) {
+            Ok(results) => {
+                for (job, (_, analyzed)) in jobs.into_iter().zip(results) {
+                    let _ = job.reply.send(analyzed.to_result());
+                }
+            }
+            // 어떤 텍스트 때문에 실패했는지 알 수 없으므로 하나씩 다시 분석하여 각자의 결과를 전달함
+            Err(_) => {
+                for job in jobs {
+                    let res = kiwi
+                        .analyze(&job.text, top_n, match_options, None, None)
+                        .and_then(|analyzed| analyzed.to_result());
+
+                    let _ = job.reply.send(res);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::oneshot;
+
+    use super::{group_jobs, Job};
+    use crate::{AnalysisResult, Match, Result};
+
+    fn job(text: &str, top_n: i32) -> (Job, oneshot::Receiver<Result<AnalysisResult>>) {
+        let (reply, receiver) = oneshot::channel();
+
+        let job = Job {
+            text: text.to_owned(),
+            top_n,
+            match_options: Match::new(),
+            reply,
+        };
+
+        (job, receiver)
+    }
+
+    #[test]
+    fn test_group_jobs_skips_cancelled() {
+        let (a, _a) = job("a", 1);
+        let (b, b_receiver) = job("b", 1);
+        let (c, _c) = job("c", 2);
+        let (d, _d) = job("d", 1);
+
+        // 결과를 기다리던 쪽이 취소됨
+        drop(b_receiver);
+
+        let groups = group_jobs(vec![a, b, c, d]);
+
+        let groups = groups
+            .iter()
+            .map(|((top_n, _), jobs)| {
+                let texts = jobs.iter().map(|job| job.text.as_str()).collect::<Vec<_>>();
+                (*top_n, texts)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(groups, vec![(1, vec!["a", "d"]), (2, vec!["c"])]);
+    }
+}
@@ -0,0 +1,729 @@
+//! 로컬 HTTP 형태소 분석 서버
+//!
+//! 하나의 [Kiwi] 인스턴스를 공유하며 JSON으로 요청과 응답을 주고받습니다.
+//!
+//! ```sh
+//! kiwi-server --addr 127.0.0.1:8080 --dict user.dict
+//! curl -s localhost:8080/analyze -d '{"text": "만둣국 먹고 싶다.", "top_n": 2, "match": ["url", "email"]}'
+//! ```
+//!
+//! | 경로 | 메서드 | 요청 | 응답 |
+//! | --- | --- | --- | --- |
+//! | `/health` | GET | | `{"status": "ok", "version": ...}` |
+//! | `/analyze` | POST | `{"text", "top_n"?, "match"?}` | [AnalysisResult] |
+//! | `/analyze/batch` | POST | `{"texts", "top_n"?, "match"?}` | [AnalysisResult]의 목록. `texts`는 `--max-batch`개까지 허용 |
+//! | `/split` | POST | `{"text", "match"?}` | `[{"begin", "end", "text"}]` (UTF-8 바이트 기준) |
+//! | `/extract` | POST | `{"texts", "min_cnt"?, "max_word_len"?, "min_score"?, "pos_threshold"?}` | [Word]의 목록. 빈 텍스트는 무시 |
+//!
+//! `match`는 [Match]의 플래그 이름 목록이며, 생략하면 [Match::new]를 사용합니다.
+//!
+//! 분석 요청은 모두 `--workers`개의 작업 스레드에서 처리합니다.
+//!
+//! 실패한 요청은 `{"error": ...}`와 함께 4xx 또는 5xx 상태 코드를 반환합니다.
+
+use std::{net::SocketAddr, path::PathBuf, process::ExitCode, sync::Arc, thread};
+
+use axum::{
+    extract::{rejection::JsonRejection, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use clap::Parser;
+use rkiwi::{
+    extracted::Word, AnalysisResult, AsyncKiwi, AsyncKiwiOptions, Error, Kiwi, KiwiBuilder,
+    KiwiOptions, Match,
+};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    sync::{mpsc, oneshot, Semaphore},
+    task::JoinSet,
+};
+
+#[derive(Parser)]
+#[command(
+    name = "kiwi-server",
+    version,
+    about = "Kiwi 한국어 형태소 분석 HTTP 서버"
+)]
+struct Cli {
+    /// 서버 주소. 기본적으로 로컬에서만 접근할 수 있습니다.
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    addr: SocketAddr,
+
+    /// 요청에 `top_n`이 없을 때 사용할 값
+    #[arg(long, default_value_t = 1)]
+    top_n: i32,
+
+    /// 요청에서 허용하는 `top_n`의 최댓값
+    #[arg(long, default_value_t = 10)]
+    max_top_n: i32,
+
+    /// 모델 폴더의 경로. 설정하지 않으면 내장 모델을 사용합니다.
+    #[arg(long)]
+    model: Option<PathBuf>,
+
+    /// 분석에 사용할 스레드 개수. 0이면 코어 개수만큼 사용합니다.
+    #[arg(long, default_value_t = 0)]
+    threads: u32,
+
+    /// 분석 요청을 처리할 작업 스레드의 개수
+    #[arg(long, default_value_t = 1)]
+    workers: usize,
+
+    /// `/analyze/batch` 요청에서 허용하는 텍스트의 최대 개수
+    #[arg(long, default_value_t = 256)]
+    max_batch: usize,
+
+    /// 불러올 사용자 사전. 여러 번 지정할 수 있습니다.
+    #[arg(long = "dict", value_name = "PATH")]
+    dicts: Vec<String>,
+
+    /// sbg 모델을 사용합니다.
+    #[arg(long)]
+    sbg: bool,
+}
+
+/// 서버 요청 처리 중 발생한 에러
+enum ServerError {
+    /// 요청이 올바르지 않음
+    BadRequest(String),
+    /// 요청 처리 중 서버 내부에서 발생한 에러
+    Internal(String),
+    Kiwi(Error),
+}
+
+impl From<Error> for ServerError {
+    fn from(err: Error) -> Self {
+        Self::Kiwi(err)
+    }
+}
+
+impl From<JsonRejection> for ServerError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            Self::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            Self::Internal(message) => {
+                eprintln!("kiwi-server: failed to handle request: {}", message);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "internal server error".to_owned(),
+                )
+            }
+            Self::Kiwi(err @ (Error::InvalidInput(_) | Error::InvalidTag(_))) => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            Self::Kiwi(err @ Error::Disconnected) => {
+                (StatusCode::SERVICE_UNAVAILABLE, err.to_string())
+            }
+            Self::Kiwi(err) => {
+                eprintln!("kiwi-server: failed to handle request: {}", err);
+                (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+            }
+        };
+
+        (status, Json(serde_json::json!({ "error": message }))).into_response()
+    }
+}
+
+type ServerResult<T> = Result<Json<T>, ServerError>;
+
+struct ExtractJob {
+    texts: Vec<String>,
+    params: ExtractParams,
+    reply: oneshot::Sender<rkiwi::Result<Vec<Word<String>>>>,
+}
+
+/// [KiwiBuilder]는 스레드 간에 공유할 수 없으므로, 단어 추출은 빌더를 소유한 전용 스레드에서 처리합니다.
+#[derive(Clone)]
+struct Extractor {
+    sender: mpsc::Sender<ExtractJob>,
+}
+
+impl Extractor {
+    /// 전용 스레드에서 [KiwiBuilder]를 생성하고, 빌드한 [Kiwi]와 함께 반환합니다.
+    fn spawn(cli: &Cli) -> rkiwi::Result<(Kiwi, Self)> {
+        let (sender, mut receiver) = mpsc::channel::<ExtractJob>(16);
+        let (built, on_built) = std::sync::mpsc::channel();
+
+        let model = cli.model.clone();
+        let threads = cli.threads;
+        let dicts = cli.dicts.clone();
+        let options = KiwiOptions::default().model_type_sbg(cli.sbg);
+
+        thread::Builder::new()
+            .name("kiwi-extractor".to_owned())
+            .spawn(move || {
+                let builder = (|| {
+                    let mut builder = match &model {
+                        Some(model) => KiwiBuilder::with_model_path(model, threads, options)?,
+                        None => KiwiBuilder::new(threads, options)?,
+                    };
+
+                    for dict in &dicts {
+                        builder = builder.load_dict(dict)?;
+                    }
+
+                    let kiwi = builder.build(None, None)?;
+                    Ok((builder, kiwi))
+                })();
+
+                let builder = match builder {
+                    Ok((builder, kiwi)) => {
+                        let _ = built.send(Ok(kiwi));
+                        builder
+                    }
+                    Err(err) => {
+                        let _ = built.send(Err(err));
+                        return;
+                    }
+                };
+
+                while let Some(job) = receiver.blocking_recv() {
+                    let _ = job.reply.send(extract(&builder, &job.texts, &job.params));
+                }
+            })
+            .expect("can't spawn kiwi extractor thread");
+
+        let kiwi = on_built.recv().map_err(|_| Error::Disconnected)??;
+
+        Ok((kiwi, Self { sender }))
+    }
+
+    async fn extract(
+        &self,
+        texts: Vec<String>,
+        params: ExtractParams,
+    ) -> rkiwi::Result<Vec<Word<String>>> {
+        let (reply, receiver) = oneshot::channel();
+
+        self.sender
+            .send(ExtractJob {
+                texts,
+                params,
+                reply,
+            })
+            .await
+            .map_err(|_| Error::Disconnected)?;
+
+        receiver.await.map_err(|_| Error::Disconnected)?
+    }
+}
+
+fn extract(
+    builder: &KiwiBuilder,
+    texts: &[String],
+    params: &ExtractParams,
+) -> rkiwi::Result<Vec<Word<String>>> {
+    let extracted = builder.extract_words(
+        |i| texts.get(i as usize).cloned().unwrap_or_default(),
+        params.min_cnt,
+        params.max_word_len,
+        params.min_score,
+        params.pos_threshold,
+    )?;
+
+    extracted.to_vec()
+}
+
+#[derive(Clone)]
+struct AppState {
+    kiwi: AsyncKiwi,
+    extractor: Extractor,
+    /// [AsyncKiwi]를 거치지 않는 분석 작업의 동시 실행 개수를 `--workers`로 제한함
+    permits: Arc<Semaphore>,
+    top_n: i32,
+    max_top_n: i32,
+    max_batch: usize,
+}
+
+impl AppState {
+    fn new(cli: &Cli) -> rkiwi::Result<Self> {
+        let (kiwi, extractor) = Extractor::spawn(cli)?;
+        let workers = cli.workers.max(1);
+
+        Ok(Self {
+            kiwi: AsyncKiwi::new(kiwi, AsyncKiwiOptions::default().workers(workers)),
+            extractor,
+            permits: Arc::new(Semaphore::new(workers)),
+            top_n: cli.top_n,
+            max_top_n: cli.max_top_n,
+            max_batch: cli.max_batch,
+        })
+    }
+
+    fn top_n(&self, top_n: Option<i32>) -> Result<i32, ServerError> {
+        match top_n {
+            None => Ok(self.top_n),
+            Some(top_n) if (1..=self.max_top_n).contains(&top_n) => Ok(top_n),
+            Some(top_n) => Err(ServerError::BadRequest(format!(
+                "top_n must be between 1 and {}, got {}",
+                self.max_top_n, top_n
+            ))),
+        }
+    }
+
+    /// 비동기 런타임의 스레드를 막지 않도록 분석 작업을 blocking 스레드에서 실행합니다.
+    ///
+    /// 동시에 실행되는 작업은 `--workers`개로 제한됩니다.
+    async fn blocking<T, F>(&self, f: F) -> Result<T, ServerError>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> rkiwi::Result<T> + Send + 'static,
+    {
+        let _permit = self
+            .permits
+            .acquire()
+            .await
+            .map_err(|_| ServerError::Kiwi(Error::Disconnected))?;
+
+        tokio::task::spawn_blocking(f)
+            .await
+            .map_err(|err| ServerError::Internal(err.to_string()))?
+            .map_err(ServerError::Kiwi)
+    }
+}
+
+#[derive(Deserialize)]
+struct AnalyzeRequest {
+    text: String,
+    top_n: Option<i32>,
+    #[serde(rename = "match", default)]
+    match_options: Match,
+}
+
+#[derive(Deserialize)]
+struct BatchAnalyzeRequest {
+    texts: Vec<String>,
+    top_n: Option<i32>,
+    #[serde(rename = "match", default)]
+    match_options: Match,
+}
+
+#[derive(Deserialize)]
+struct SplitRequest {
+    text: String,
+    #[serde(rename = "match", default)]
+    match_options: Match,
+}
+
+#[derive(Serialize)]
+struct Sentence {
+    begin: usize,
+    end: usize,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct ExtractRequest {
+    texts: Vec<String>,
+    #[serde(flatten)]
+    params: ExtractParams,
+}
+
+/// [KiwiBuilder::extract_words] 참고
+#[derive(Deserialize)]
+#[serde(default)]
+struct ExtractParams {
+    min_cnt: i32,
+    max_word_len: i32,
+    min_score: f32,
+    pos_threshold: f32,
+}
+
+impl Default for ExtractParams {
+    fn default() -> Self {
+        Self {
+            min_cnt: 10,
+            max_word_len: 10,
+            min_score: 0.25,
+            pos_threshold: -3.0,
+        }
+    }
+}
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "version": rkiwi::kiwi_version(),
+    }))
+}
+
+async fn analyze(
+    State(state): State<AppState>,
+    request: Result<Json<AnalyzeRequest>, JsonRejection>,
+) -> ServerResult<AnalysisResult> {
+    let Json(request) = request?;
+    let top_n = state.top_n(request.top_n)?;
+
+    let result = state
+        .kiwi
+        .analyze(request.text, top_n, request.match_options)
+        .await?;
+
+    Ok(Json(result))
+}
+
+async fn analyze_batch(
+    State(state): State<AppState>,
+    request: Result<Json<BatchAnalyzeRequest>, JsonRejection>,
+) -> ServerResult<Vec<AnalysisResult>> {
+    let Json(request) = request?;
+    let top_n = state.top_n(request.top_n)?;
+
+    if request.texts.len() > state.max_batch {
+        return Err(ServerError::BadRequest(format!(
+            "texts must contain at most {} items, got {}",
+            state.max_batch,
+            request.texts.len()
+        )));
+    }
+
+    // 각 텍스트를 AsyncKiwi의 대기열로 보내 다른 요청과 함께 묶어 분석함
+    // 요청이 취소되면 JoinSet이 해제되면서 남은 작업도 취소됨
+    let mut tasks = JoinSet::new();
+
+    for (i, text) in request.texts.into_iter().enumerate() {
+        let kiwi = state.kiwi.clone();
+        let match_options = request.match_options;
+
+        tasks.spawn(async move { (i, kiwi.analyze(text, top_n, match_options).await) });
+    }
+
+    let mut results = Vec::with_capacity(tasks.len());
+
+    while let Some(res) = tasks.join_next().await {
+        let (i, result) = res.map_err(|err| ServerError::Internal(err.to_string()))?;
+        results.push((i, result?));
+    }
+
+    results.sort_unstable_by_key(|(i, _)| *i);
+
+    Ok(Json(
+        results.into_iter().map(|(_, result)| result).collect(),
+    ))
+}
+
+async fn split(
+    State(state): State<AppState>,
+    request: Result<Json<SplitRequest>, JsonRejection>,
+) -> ServerResult<Vec<Sentence>> {
+    let Json(request) = request?;
+
+    let kiwi = state.kiwi.kiwi().clone();
+
+    let sentences = state
+        .blocking(move || {
+            let sentences = kiwi.split_into_sents(&request.text, request.match_options, false)?;
+
+            Ok(sentences
+                .to_vec()?
+                .into_iter()
+                .map(|(begin, end)| Sentence {
+                    begin,
+                    end,
+                    text: request.text[begin..end].to_owned(),
+                })
+                .collect())
+        })
+        .await?;
+
+    Ok(Json(sentences))
+}
+
+async fn extract_words(
+    State(state): State<AppState>,
+    request: Result<Json<ExtractRequest>, JsonRejection>,
+) -> ServerResult<Vec<Word<String>>> {
+    let Json(mut request) = request?;
+
+    // 리더가 빈 문자열을 반환하면 입력이 끝난 것으로 처리되므로, 빈 텍스트는 미리 제외함
+    request.texts.retain(|text| !text.is_empty());
+
+    let words = state
+        .extractor
+        .extract(request.texts, request.params)
+        .await?;
+
+    Ok(Json(words))
+}
+
+fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/analyze", post(analyze))
+        .route("/analyze/batch", post(analyze_batch))
+        .route("/split", post(split))
+        .route("/extract", post(extract_words))
+        .with_state(state)
+}
+
+/// Ctrl+C 또는 SIGTERM을 받으면 완료됩니다.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            eprintln!("kiwi-server: failed to listen for ctrl-c: {}", err);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(err) => {
+                eprintln!("kiwi-server: failed to listen for SIGTERM: {}", err);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.top_n < 1 || cli.top_n > cli.max_top_n {
+        return Err(format!(
+            "--top-n must be between 1 and --max-top-n ({})",
+            cli.max_top_n
+        )
+        .into());
+    }
+
+    let state = AppState::new(&cli)?;
+
+    let listener = tokio::net::TcpListener::bind(cli.addr).await?;
+    eprintln!(
+        "kiwi-server: listening on http://{}",
+        listener.local_addr()?
+    );
+
+    axum::serve(listener, router(state))
+        .with_graceful_shutdown(shutdown_signal())
+        .await?;
+
+    eprintln!("kiwi-server: shut down");
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("kiwi-server: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{to_bytes, Body},
+        http::{header, Request, StatusCode},
+        Router,
+    };
+    use clap::Parser;
+    use tower::ServiceExt;
+
+    use super::{router, AppState, Cli};
+
+    fn app(args: &[&str]) -> anyhow::Result<Router> {
+        let cli = Cli::try_parse_from(["kiwi-server", "--threads", "1"].iter().chain(args))?;
+        Ok(router(AppState::new(&cli)?))
+    }
+
+    async fn send(
+        app: Router,
+        request: Request<Body>,
+    ) -> anyhow::Result<(StatusCode, serde_json::Value)> {
+        let response = app.oneshot(request).await?;
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await?;
+
+        Ok((status, serde_json::from_slice(&body)?))
+    }
+
+    fn post(uri: &str, body: serde_json::Value) -> Request<Body> {
+        Request::post(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_health() -> anyhow::Result<()> {
+        let request = Request::get("/health").body(Body::empty())?;
+        let (status, body) = send(app(&[])?, request).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "ok");
+        assert_eq!(body["version"], rkiwi::kiwi_version());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bad_top_n() -> anyhow::Result<()> {
+        let app = app(&["--max-top-n", "3"])?;
+
+        for top_n in [0, 4] {
+            let request = post(
+                "/analyze",
+                serde_json::json!({ "text": "밥", "top_n": top_n }),
+            );
+            let (status, body) = send(app.clone(), request).await?;
+
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert!(
+                body["error"].as_str().unwrap().contains("top_n"),
+                "{}",
+                body
+            );
+        }
+
+        let request = post("/analyze", serde_json::json!({ "text": "밥", "top_n": 3 }));
+        let (status, _) = send(app, request).await?;
+
+        assert_eq!(status, StatusCode::OK);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bad_match() -> anyhow::Result<()> {
+        let app = app(&[])?;
+
+        let request = post(
+            "/analyze",
+            serde_json::json!({ "text": "밥", "match": ["url", "nope"] }),
+        );
+        let (status, body) = send(app.clone(), request).await?;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].is_string(), "{}", body);
+
+        let request = post(
+            "/analyze",
+            serde_json::json!({ "text": "https://example.com", "match": ["url"] }),
+        );
+        let (status, body) = send(app, request).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["candidates"][0]["tokens"][0]["token"]["tag"], "W_URL");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_analyze_batch() -> anyhow::Result<()> {
+        let app = app(&["--max-batch", "2"])?;
+
+        let request = post(
+            "/analyze/batch",
+            serde_json::json!({ "texts": ["밥을 먹었다.", "https://example.com"], "match": ["url"] }),
+        );
+        let (status, body) = send(app.clone(), request).await?;
+
+        // 결과는 요청한 텍스트의 순서를 따름
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body[0]["candidates"][0]["tokens"][0]["form"], "밥");
+        assert_eq!(
+            body[1]["candidates"][0]["tokens"][0]["token"]["tag"],
+            "W_URL"
+        );
+
+        let request = post(
+            "/analyze/batch",
+            serde_json::json!({ "texts": ["밥", "밥", "밥"] }),
+        );
+        let (status, _) = send(app, request).await?;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_extract_skips_empty_texts() -> anyhow::Result<()> {
+        let app = app(&[])?;
+
+        let texts = [
+            "키윗키윗이 정말 좋다.",
+            "키윗키윗을 먹었다.",
+            "나는 키윗키윗이 좋다.",
+        ]
+        .repeat(10);
+        let params = serde_json::json!({ "min_cnt": 5, "min_score": 0.0, "pos_threshold": -10.0 });
+
+        let extract = |texts: Vec<&str>| {
+            let mut body = params.clone();
+            body["texts"] = serde_json::json!(texts);
+            send(app.clone(), post("/extract", body))
+        };
+
+        let (status, expected) = extract(texts.clone()).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(!expected.as_array().unwrap().is_empty(), "{}", expected);
+
+        // 빈 텍스트 뒤의 텍스트도 모두 사용함
+        let mut with_empty = texts.clone();
+        with_empty.insert(0, "");
+        with_empty.insert(15, "");
+
+        let (status, body) = extract(with_empty).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_split_offsets() -> anyhow::Result<()> {
+        let text = "안녕하세요. 만나서 반갑습니다.";
+
+        let request = post("/split", serde_json::json!({ "text": text }));
+        let (status, body) = send(app(&[])?, request).await?;
+
+        assert_eq!(status, StatusCode::OK);
+
+        let sentences = body.as_array().unwrap();
+        assert_eq!(sentences.len(), 2, "{}", body);
+
+        // 위치는 UTF-8 바이트 기준
+        for (sentence, expected) in sentences.iter().zip(["안녕하세요.", "만나서 반갑습니다."])
+        {
+            let begin = sentence["begin"].as_u64().unwrap() as usize;
+            let end = sentence["end"].as_u64().unwrap() as usize;
+
+            assert_eq!(begin, text.find(expected).unwrap());
+            assert_eq!(&text[begin..end], expected);
+            assert_eq!(sentence["text"], expected);
+        }
+
+        Ok(())
+    }
+}
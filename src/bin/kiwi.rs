@@ -0,0 +1,356 @@
+//! 명령줄 형태소 분석기
+//!
+//! 표준 입력 또는 파일을 한 줄씩 분석하여 출력합니다.
+//!
+//! ```sh
+//! echo "만둣국 먹고 싶다." | kiwi --format conllu
+//! kiwi --dict user.dict --url --email --format json input.txt
+//! ```
+
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Args, Parser, ValueEnum};
+use rkiwi::{
+    analyzed::OwnedToken, Analyzed, AnalyzerConfig, DefaultTypoSet, KiwiConfig, KiwiOptions, Match,
+};
+
+#[derive(Parser)]
+#[command(name = "kiwi", version, about = "Kiwi 한국어 형태소 분석기")]
+struct Cli {
+    /// 분석할 파일. 없거나 `-`인 경우 표준 입력을 읽습니다.
+    files: Vec<PathBuf>,
+
+    /// 출력 형식
+    #[arg(short, long, value_enum, default_value_t = Format::Plain)]
+    format: Format,
+
+    /// 분석 결과 후보를 상위 몇개까지 출력할지 설정합니다. (conllu 형식은 첫 번째 후보만 출력)
+    #[arg(short = 'n', long, default_value_t = 1)]
+    top_n: i32,
+
+    #[command(flatten)]
+    build: BuildArgs,
+
+    #[command(flatten)]
+    analyzer: AnalyzerArgs,
+
+    #[command(flatten)]
+    match_options: MatchArgs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// 한 줄에 한 후보씩 `형태/태그`를 공백으로 구분하여 출력
+    Plain,
+    /// 입력 한 줄마다 분석 결과를 JSON 한 줄로 출력
+    Json,
+    /// CoNLL-U 형식으로 출력
+    Conllu,
+    /// 형태소마다 후보 번호, 형태, 태그, 시작, 끝 위치(UTF-8 바이트)를 탭으로 구분하여 출력
+    Tsv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TypoSet {
+    None,
+    Basic,
+    Continual,
+    BasicContinual,
+    Lengthening,
+    BasicContinualLengthening,
+}
+
+impl From<TypoSet> for DefaultTypoSet {
+    fn from(typo: TypoSet) -> Self {
+        match typo {
+            TypoSet::None => DefaultTypoSet::WithoutTypo,
+            TypoSet::Basic => DefaultTypoSet::BasicTypoSet,
+            TypoSet::Continual => DefaultTypoSet::ContinualTypoSet,
+            TypoSet::BasicContinual => DefaultTypoSet::BasicTypoSetWithContinual,
+            TypoSet::Lengthening => DefaultTypoSet::LengtheningTypoSet,
+            TypoSet::BasicContinualLengthening => {
+                DefaultTypoSet::BasicTypoSetWithContinualAndLengthening
+            }
+        }
+    }
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Build options")]
+struct BuildArgs {
+    /// 모델 폴더의 경로. 설정하지 않으면 내장 모델을 사용합니다.
+    #[arg(long)]
+    model: Option<PathBuf>,
+
+    /// 분석에 사용할 스레드 개수. 0이면 코어 개수만큼 사용합니다.
+    #[arg(long, default_value_t = 0)]
+    threads: u32,
+
+    /// 불러올 사용자 사전. 여러 번 지정할 수 있습니다.
+    #[arg(long = "dict", value_name = "PATH")]
+    dicts: Vec<PathBuf>,
+
+    /// 오타 교정에 사용할 기본 오타 정의
+    #[arg(long, value_enum)]
+    typo: Option<TypoSet>,
+
+    /// 이 값을 넘어가는 비용이 필요한 오타는 교정하지 않습니다.
+    #[arg(long, requires = "typo")]
+    typo_cost_threshold: Option<f32>,
+
+    /// sbg 모델을 사용합니다.
+    #[arg(long)]
+    sbg: bool,
+
+    /// 기본 사전을 불러오지 않습니다.
+    #[arg(long)]
+    no_default_dict: bool,
+
+    /// 내장 오타 사전을 불러오지 않습니다.
+    #[arg(long)]
+    no_typo_dict: bool,
+
+    /// 내장 다어절 사전을 불러오지 않습니다.
+    #[arg(long)]
+    no_multi_dict: bool,
+}
+
+#[derive(Args)]
+#[command(next_help_heading = "Analyzer options")]
+struct AnalyzerArgs {
+    /// 음운론적 이형태를 통합합니다.
+    #[arg(long)]
+    integrate_allomorph: Option<bool>,
+
+    /// 사전 미등재 형태소의 최대 길이
+    #[arg(long)]
+    max_unk_form_size: Option<u32>,
+
+    /// 무시할 수 있는 공백의 최대 개수
+    #[arg(long)]
+    space_tolerance: Option<u32>,
+
+    /// 분석 후보를 잘라낼 점수 차이
+    #[arg(long)]
+    cut_off_threshold: Option<f32>,
+
+    /// 사전 미등재 형태소 점수의 배율
+    #[arg(long)]
+    unk_form_score_scale: Option<f32>,
+
+    /// 사전 미등재 형태소 점수의 편향
+    #[arg(long)]
+    unk_form_score_bias: Option<f32>,
+
+    /// 공백 하나당 점수 페널티
+    #[arg(long)]
+    space_penalty: Option<f32>,
+}
+
+/// 각 옵션은 `Match`의 같은 이름의 메서드 참고
+#[derive(Args)]
+#[command(next_help_heading = "Match options")]
+struct MatchArgs {
+    /// url, email, hashtag, mention, serial 옵션을 모두 활성화합니다. emoji는 따로 지정해야 합니다.
+    #[arg(long)]
+    all: bool,
+    #[arg(long)]
+    url: bool,
+    #[arg(long)]
+    email: bool,
+    #[arg(long)]
+    hashtag: bool,
+    #[arg(long)]
+    mention: bool,
+    #[arg(long)]
+    serial: bool,
+    #[arg(long)]
+    emoji: bool,
+    #[arg(long)]
+    normalize_coda: bool,
+    #[arg(long)]
+    join_noun_prefix: bool,
+    #[arg(long)]
+    join_noun_suffix: bool,
+    #[arg(long)]
+    join_verb_suffix: bool,
+    #[arg(long)]
+    join_adj_suffix: bool,
+    #[arg(long)]
+    join_adv_suffix: bool,
+    #[arg(long)]
+    split_complex: bool,
+    #[arg(long)]
+    z_coda: bool,
+    #[arg(long)]
+    compatible_jamo: bool,
+    #[arg(long)]
+    split_saisiot: bool,
+    #[arg(long)]
+    merge_saisiot: bool,
+}
+
+impl MatchArgs {
+    fn finish(&self) -> Match {
+        // Match::new()는 모든 플래그가 비활성화되어 있으므로 지정한 옵션만 활성화됨
+        Match::new()
+            .url(self.all || self.url)
+            .email(self.all || self.email)
+            .hashtag(self.all || self.hashtag)
+            .mention(self.all || self.mention)
+            .serial(self.all || self.serial)
+            .emoji(self.emoji)
+            .normalize_coda(self.normalize_coda)
+            .join_noun_prefix(self.join_noun_prefix)
+            .join_noun_suffix(self.join_noun_suffix)
+            .join_verb_suffix(self.join_verb_suffix)
+            .join_adj_suffix(self.join_adj_suffix)
+            .join_adv_suffix(self.join_adv_suffix)
+            .split_complex(self.split_complex)
+            .z_coda(self.z_coda)
+            .compatible_jamo(self.compatible_jamo)
+            .split_saisiot(self.split_saisiot)
+            .merge_saisiot(self.merge_saisiot)
+    }
+}
+
+impl BuildArgs {
+    /// 빌드 옵션과 분석 옵션을 [KiwiConfig]로 변환합니다.
+    fn config(&self, analyzer: &AnalyzerArgs) -> KiwiConfig {
+        let options = KiwiOptions::default()
+            .model_type_sbg(self.sbg)
+            .load_default_dict(!self.no_default_dict)
+            .load_typo_dict(!self.no_typo_dict)
+            .load_multi_dict(!self.no_multi_dict);
+
+        KiwiConfig {
+            model_path: self.model.clone(),
+            num_threads: Some(self.threads),
+            options,
+            dicts: self.dicts.clone(),
+            typo: self.typo.map(DefaultTypoSet::from),
+            typo_cost_threshold: self.typo_cost_threshold,
+            analyzer: AnalyzerConfig {
+                integrate_allomorph: analyzer.integrate_allomorph,
+                max_unk_form_size: analyzer.max_unk_form_size,
+                space_tolerance: analyzer.space_tolerance,
+                cut_off_threshold: analyzer.cut_off_threshold,
+                unk_form_score_scale: analyzer.unk_form_score_scale,
+                unk_form_score_bias: analyzer.unk_form_score_bias,
+                space_penalty: analyzer.space_penalty,
+            },
+            ..Default::default()
+        }
+    }
+}
+
+fn write_analyzed(
+    out: &mut impl Write,
+    format: Format,
+    text: &str,
+    analyzed: &Analyzed,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        Format::Plain => {
+            for candidate in &analyzed.to_result()?.candidates {
+                let line = candidate
+                    .tokens
+                    .iter()
+                    .map(|token| format!("{}/{}", token.form, token.token.tag))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                writeln!(out, "{}", line)?;
+            }
+        }
+        Format::Json => {
+            let line = serde_json::json!({
+                "text": text,
+                "candidates": analyzed.to_result()?.candidates,
+            });
+            writeln!(out, "{}", line)?;
+        }
+        Format::Conllu => {
+            if let Some(conllu) = analyzed.to_conllu(0)? {
+                write!(out, "{}", conllu)?;
+            }
+        }
+        Format::Tsv => {
+            for (i, candidate) in analyzed.to_result()?.candidates.iter().enumerate() {
+                for OwnedToken { form, token } in &candidate.tokens {
+                    writeln!(
+                        out,
+                        "{}\t{}\t{}\t{}\t{}",
+                        i, form, token.tag, token.byte_begin, token.byte_end
+                    )?;
+                }
+            }
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn open_input(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path.as_os_str() == "-" {
+        Ok(Box::new(io::stdin().lock()))
+    } else {
+        let file = File::open(path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))?;
+
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let kiwi = cli.build.config(&cli.analyzer).build()?;
+
+    let match_options = cli.match_options.finish();
+
+    let stdin = [PathBuf::from("-")];
+    let files = if cli.files.is_empty() {
+        &stdin[..]
+    } else {
+        &cli.files[..]
+    };
+
+    let mut out = BufWriter::new(io::stdout().lock());
+
+    // 표준 입력의 lock을 미리 잡아두지 않도록 읽을 차례에 입력을 열고, 다 읽으면 닫음
+    for path in files {
+        let input = open_input(path)?;
+
+        for line in input.lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let analyzed = kiwi.analyze(&line, cli.top_n, match_options, None, None)?;
+            write_analyzed(&mut out, cli.format, &line, &analyzed)?;
+        }
+    }
+
+    out.flush()?;
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("kiwi: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -1,18 +1,18 @@
 use std::{
-    ffi::{c_void, CString},
+    ffi::c_void,
     path::{Path, PathBuf},
-    str::FromStr,
+    sync::Arc,
 };
 
 use either::Either;
-use parking_lot::{Mutex, RwLock};
+use parking_lot::Mutex;
 use widestring::U16String;
 
 use crate::{
     bindings::*,
-    kiwi_error,
-    trampoline::{reader_trampoline, reader_w_trampoline, replacer_trampoline},
-    typo, Error, Extracted, Kiwi, KiwiRc, POSTag, Result,
+    kiwi_error, path_to_cstring, to_cstring,
+    trampoline::{reader_trampoline, reader_w_trampoline, replacer_trampoline, Callback},
+    typo, Error, Extracted, Kiwi, KiwiHandle, KiwiRc, POSTag, Result,
 };
 
 /// [Kiwi] 구조체를 생성할 때 사용하는 옵션 구조체
@@ -22,6 +22,8 @@ use crate::{
 /// # Default
 ///
 /// 모델은 knlm, 나머지 옵션은 모두 활성화 됨
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct KiwiOptions {
     integrate_allomorph: bool,
     load_default_dict: bool,
@@ -118,6 +120,60 @@ impl Default for KiwiOptions {
     }
 }
 
+/// 생략한 옵션은 기본값을 사용합니다.
+///
+/// `model_type_knlm`과 `model_type_sbg`는 둘 중 하나만 켜지도록 [KiwiOptions::model_type_knlm], [KiwiOptions::model_type_sbg]를 거쳐 설정하며,
+/// 두 값을 함께 적은 경우 서로 반대여야 합니다.
+///
+/// 알 수 없는 옵션이 있으면 에러를 반환합니다.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KiwiOptions {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Fields {
+            integrate_allomorph: Option<bool>,
+            load_default_dict: Option<bool>,
+            load_typo_dict: Option<bool>,
+            load_multi_dict: Option<bool>,
+            model_type_knlm: Option<bool>,
+            model_type_sbg: Option<bool>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        let mut r = KiwiOptions::default();
+
+        if let Some(x) = fields.integrate_allomorph {
+            r = r.integrate_allomorph(x);
+        }
+        if let Some(x) = fields.load_default_dict {
+            r = r.load_default_dict(x);
+        }
+        if let Some(x) = fields.load_typo_dict {
+            r = r.load_typo_dict(x);
+        }
+        if let Some(x) = fields.load_multi_dict {
+            r = r.load_multi_dict(x);
+        }
+
+        match (fields.model_type_knlm, fields.model_type_sbg) {
+            (Some(knlm), Some(sbg)) if knlm == sbg => {
+                return Err(serde::de::Error::custom(
+                    "`model_type_knlm` and `model_type_sbg` must be opposite",
+                ));
+            }
+            (_, Some(sbg)) => r = r.model_type_sbg(sbg),
+            (Some(knlm), None) => r = r.model_type_knlm(knlm),
+            (None, None) => {}
+        }
+
+        Ok(r)
+    }
+}
+
 pub struct KiwiBuilder {
     handle: KiwiRc<Mutex<kiwi_builder_h>>,
 }
@@ -142,12 +198,11 @@ impl KiwiBuilder {
             .join("models")
             .join("base");
 
-        let model_path =
-            CString::new(model_path.as_os_str().to_os_string().into_string().unwrap()).unwrap();
+        let c_model_path = path_to_cstring(&model_path)?;
 
         let handle = unsafe {
             kiwi_builder_init(
-                model_path.as_ptr(),
+                c_model_path.as_ptr(),
                 num_threads.into().unwrap_or(0) as i32,
                 options.finish() as i32,
             )
@@ -155,7 +210,10 @@ impl KiwiBuilder {
 
         if handle.is_null() {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::ModelLoad {
+                path: model_path.to_path_buf(),
+                message: err,
+            });
         }
 
         Ok(Self {
@@ -178,19 +236,13 @@ impl KiwiBuilder {
         num_threads: impl Into<Option<u32>>,
         options: KiwiOptions,
     ) -> Result<Self> {
-        let model_path = CString::new(
-            model_path
-                .as_ref()
-                .as_os_str()
-                .to_os_string()
-                .into_string()
-                .unwrap(),
-        )
-        .unwrap();
+        let model_path = model_path.as_ref();
+
+        let c_model_path = path_to_cstring(model_path)?;
 
         let handle = unsafe {
             kiwi_builder_init(
-                model_path.as_ptr(),
+                c_model_path.as_ptr(),
                 num_threads.into().unwrap_or(0) as i32,
                 options.finish() as i32,
             )
@@ -198,7 +250,10 @@ impl KiwiBuilder {
 
         if handle.is_null() {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::ModelLoad {
+                path: model_path.to_path_buf(),
+                message: err,
+            });
         }
 
         Ok(Self {
@@ -219,8 +274,8 @@ impl KiwiBuilder {
     /// * `pos` - 품사 태그 ([POSTag])
     /// * `score` - 점수
     pub fn add_word(self, word: &str, pos_tag: POSTag, score: f32) -> Result<Self> {
-        let word = CString::from_str(word).unwrap();
-        let pos_tag = CString::from_str(pos_tag.as_str()).unwrap();
+        let word = to_cstring(word)?;
+        let pos_tag = to_cstring(pos_tag.as_str())?;
 
         let res = unsafe {
             let handle = self.handle.lock();
@@ -258,9 +313,9 @@ impl KiwiBuilder {
         score: f32,
         origin_word: &str,
     ) -> Result<Self> {
-        let alias = CString::from_str(alias).unwrap();
-        let pos_tag = CString::from_str(pos_tag.as_str()).unwrap();
-        let origin_word = CString::from_str(origin_word).unwrap();
+        let alias = to_cstring(alias)?;
+        let pos_tag = to_cstring(pos_tag.as_str())?;
+        let origin_word = to_cstring(origin_word)?;
 
         let res = unsafe {
             let handle = self.handle.lock();
@@ -352,12 +407,12 @@ impl KiwiBuilder {
             }
         };
 
-        let form = CString::from_str(form).unwrap();
+        let form = to_cstring(form)?;
 
         let analyzed_morphs = analyzed_morphs
             .iter()
-            .map(|morph| CString::from_str(morph).unwrap())
-            .collect::<Vec<_>>();
+            .map(|morph| to_cstring(morph))
+            .collect::<Result<Vec<_>>>()?;
         let mut analyzed_morphs = analyzed_morphs
             .iter()
             .map(|morph| morph.as_ptr())
@@ -365,8 +420,8 @@ impl KiwiBuilder {
 
         let analyzed_pos_tags = analyzed_pos_tags
             .iter()
-            .map(|pos_tag| CString::from_str(pos_tag.as_str()).unwrap())
-            .collect::<Vec<_>>();
+            .map(|pos_tag| to_cstring(pos_tag.as_str()))
+            .collect::<Result<Vec<_>>>()?;
         let mut analyzed_pos_tags = analyzed_pos_tags
             .iter()
             .map(|pos_tag| pos_tag.as_ptr())
@@ -403,6 +458,12 @@ impl KiwiBuilder {
     /// * `pos_tag` - 변형할 형태소의 품사 태그
     /// * `replacer` - 변형 결과를 제공하는데 쓰일 함수
     /// * `score` - 기본적으로는 0을 사용합니다. 0보다 클 경우 이 변형 결과가 더 높은 우선 순위를, 작을 경우 더 낮은 우선 순위를 갖습니다.
+    ///
+    /// # Errors
+    ///
+    /// `replacer`에서 panic이 발생한 경우 [Error::CallbackPanic]을 반환합니다. panic 이후의 형태소는 변형되지 않습니다.
+    ///
+    /// `replacer`가 null 문자가 포함된 문자열을 반환한 경우 [Error::InvalidInput]을 반환합니다. 이후의 형태소는 변형되지 않습니다.
     pub fn add_rule<F>(self, pos_tag: POSTag, replacer: F, score: f32) -> Result<Self>
     where
         F: FnMut(&str) -> String,
@@ -417,13 +478,13 @@ impl KiwiBuilder {
         // println!("{}", std::mem::size_of::<F>());
         // println!("{}", std::mem::size_of_val(&replacer));
 
-        let replacer = Box::into_raw(Box::new(Box::new(replacer)));
+        let replacer = Box::into_raw(Box::new(Callback::new(replacer)));
 
         // println!("{}", std::mem::size_of_val(&replacer));
 
-        let pos_tag = CString::from_str(pos_tag.as_str()).unwrap();
+        let pos_tag = to_cstring(pos_tag.as_str())?;
 
-        let res = unsafe {
+        let (res, replacer) = unsafe {
             // println!("replacer {:?}", replacer);
             let handle = self.handle.lock();
             let r = kiwi_builder_add_rule(
@@ -434,12 +495,53 @@ impl KiwiBuilder {
                 score,
             );
 
-            drop(Box::from_raw(replacer));
+            (r, Box::from_raw(replacer))
+        };
+
+        replacer.finish()?;
+
+        if res < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        Ok(self)
+    }
 
-            r
+    /// 정규 표현식에 일치하는 텍스트를 하나의 형태소로 분석하도록 등록합니다.
+    ///
+    /// 상품 코드나 티켓 번호처럼 일정한 패턴을 갖는 텍스트를 특정 품사로 분석할 때 유용합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `pattern` - 정규 표현식 (utf-8)
+    /// * `pos_tag` - 일치하는 텍스트에 부여할 품사 태그 ([POSTag])
+    /// * `score` - 점수. 기본적으로는 0을 사용합니다. 0보다 클 경우 이 분석 결과가 더 높은 우선 순위를, 작을 경우 더 낮은 우선 순위를 갖습니다.
+    ///
+    /// # Errors
+    ///
+    /// 정규 표현식이 올바르지 않은 경우 에러를 반환합니다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rkiwi::{KiwiBuilder, POSTag};
+    ///
+    /// let kiwi_builder = KiwiBuilder::new(None, Default::default())
+    ///     .unwrap()
+    ///     .add_re_word(r"PROJ-[0-9]+", POSTag::USER0, 0.0)
+    ///     .unwrap();
+    /// ```
+    pub fn add_re_word(self, pattern: &str, pos_tag: POSTag, score: f32) -> Result<Self> {
+        let pattern = to_cstring(pattern)?;
+        let pos_tag = to_cstring(pos_tag.as_str())?;
+
+        let res = unsafe {
+            let handle = self.handle.lock();
+            kiwi_builder_add_re_word(*handle, pattern.as_ptr(), pos_tag.as_ptr(), score)
         };
 
-        if res != 0 {
+        if res < 0 {
             let err = kiwi_error().unwrap_or_default();
             return Err(Error::Native(err));
         }
@@ -447,15 +549,55 @@ impl KiwiBuilder {
         Ok(self)
     }
 
-    pub fn load_dict(self, dict_path: &str) -> Result<Self> {
-        let dict_path = CString::from_str(dict_path).unwrap();
+    /// 정규 표현식 치환 규칙에 의해 변형된 형태소 목록을 생성하여 자동 추가합니다.
+    ///
+    /// [KiwiBuilder::add_rule]과 같지만, 함수 대신 정규 표현식과 치환 문자열을 사용합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `pos_tag` - 변형할 형태소의 품사 태그
+    /// * `pattern` - 변형할 부분을 찾을 정규 표현식 (utf-8)
+    /// * `replacement` - 치환 문자열 (utf-8). `$1`처럼 캡처 그룹을 참조할 수 있습니다.
+    /// * `score` - 기본적으로는 0을 사용합니다. 0보다 클 경우 이 변형 결과가 더 높은 우선 순위를, 작을 경우 더 낮은 우선 순위를 갖습니다.
+    ///
+    /// # Errors
+    ///
+    /// 정규 표현식이 올바르지 않은 경우 에러를 반환합니다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rkiwi::{KiwiBuilder, POSTag};
+    ///
+    /// // 어미의 "요"를 "염"으로 바꾼 변이형을 추가합니다.
+    /// let kiwi_builder = KiwiBuilder::new(None, Default::default())
+    ///     .unwrap()
+    ///     .add_re_rule(POSTag::EF, "요$", "염", -1.0)
+    ///     .unwrap();
+    /// ```
+    pub fn add_re_rule(
+        self,
+        pos_tag: POSTag,
+        pattern: &str,
+        replacement: &str,
+        score: f32,
+    ) -> Result<Self> {
+        let pos_tag = to_cstring(pos_tag.as_str())?;
+        let pattern = to_cstring(pattern)?;
+        let replacement = to_cstring(replacement)?;
 
         let res = unsafe {
             let handle = self.handle.lock();
-            kiwi_builder_load_dict(*handle, dict_path.as_ptr())
+            kiwi_builder_add_re_rule(
+                *handle,
+                pos_tag.as_ptr(),
+                pattern.as_ptr(),
+                replacement.as_ptr(),
+                score,
+            )
         };
 
-        if res != 0 {
+        if res < 0 {
             let err = kiwi_error().unwrap_or_default();
             return Err(Error::Native(err));
         }
@@ -463,6 +605,34 @@ impl KiwiBuilder {
         Ok(self)
     }
 
+    /// 사용자 사전 파일을 불러옵니다.
+    ///
+    /// 사전 파일은 한 줄에 `형태\t품사 태그\t점수` 형식으로 형태소를 하나씩 적습니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `dict_path` - 사전 파일의 경로
+    ///
+    /// # Errors
+    ///
+    /// 파일을 열 수 없거나 형식이 올바르지 않은 경우 [Error::DictParse]를 반환합니다.
+    pub fn load_dict(self, dict_path: &str) -> Result<Self> {
+        let c_dict_path = to_cstring(dict_path)?;
+
+        // 성공 시 불러온 형태소의 개수를 반환함
+        let res = unsafe {
+            let handle = self.handle.lock();
+            kiwi_builder_load_dict(*handle, c_dict_path.as_ptr())
+        };
+
+        if res < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::dict_parse(dict_path, err));
+        }
+
+        Ok(self)
+    }
+
     pub fn extract_words<F>(
         &self,
         reader: F,
@@ -474,9 +644,9 @@ impl KiwiBuilder {
     where
         F: FnMut(i32) -> String,
     {
-        let reader = Box::into_raw(Box::new(Box::new(reader)));
+        let reader = Box::into_raw(Box::new(Callback::new(reader)));
 
-        let ws = unsafe {
+        let (ws, reader) = unsafe {
             let handle = self.handle.lock();
             let ws = kiwi_builder_extract_words(
                 *handle,
@@ -488,17 +658,23 @@ impl KiwiBuilder {
                 pos_threshold,
             );
 
-            drop(Box::from_raw(reader));
-
-            ws
+            (ws, Box::from_raw(reader))
         };
 
+        if let Err(err) = reader.finish() {
+            if !ws.is_null() {
+                unsafe { kiwi_ws_close(ws) };
+            }
+
+            return Err(err);
+        }
+
         if ws.is_null() {
             let err = kiwi_error().unwrap_or_default();
             return Err(Error::Native(err));
         }
 
-        Ok(Extracted::new(ws))
+        Extracted::new(ws)
     }
 
     pub fn extract_add_words<F>(
@@ -512,9 +688,9 @@ impl KiwiBuilder {
     where
         F: FnMut(i32) -> String,
     {
-        let reader = Box::into_raw(Box::new(Box::new(reader)));
+        let reader = Box::into_raw(Box::new(Callback::new(reader)));
 
-        let ws = unsafe {
+        let (ws, reader) = unsafe {
             let handle = self.handle.lock();
             let ws = kiwi_builder_extract_add_words(
                 *handle,
@@ -526,17 +702,23 @@ impl KiwiBuilder {
                 pos_threshold,
             );
 
-            drop(Box::from_raw(reader));
-
-            ws
+            (ws, Box::from_raw(reader))
         };
 
+        if let Err(err) = reader.finish() {
+            if !ws.is_null() {
+                unsafe { kiwi_ws_close(ws) };
+            }
+
+            return Err(err);
+        }
+
         if ws.is_null() {
             let err = kiwi_error().unwrap_or_default();
             return Err(Error::Native(err));
         }
 
-        Ok(Extracted::new(ws))
+        Extracted::new(ws)
     }
 
     pub fn extract_words_w<F>(
@@ -550,9 +732,9 @@ impl KiwiBuilder {
     where
         F: FnMut(i32) -> U16String,
     {
-        let reader_w = Box::into_raw(Box::new(Box::new(reader_w)));
+        let reader_w = Box::into_raw(Box::new(Callback::new(reader_w)));
 
-        let ws = unsafe {
+        let (ws, reader_w) = unsafe {
             let handle = self.handle.lock();
             let ws = kiwi_builder_extract_words_w(
                 *handle,
@@ -564,17 +746,23 @@ impl KiwiBuilder {
                 pos_threshold,
             );
 
-            drop(Box::from_raw(reader_w));
-
-            ws
+            (ws, Box::from_raw(reader_w))
         };
 
+        if let Err(err) = reader_w.finish() {
+            if !ws.is_null() {
+                unsafe { kiwi_ws_close(ws) };
+            }
+
+            return Err(err);
+        }
+
         if ws.is_null() {
             let err = kiwi_error().unwrap_or_default();
             return Err(Error::Native(err));
         }
 
-        Ok(Extracted::new(ws))
+        Extracted::new(ws)
     }
 
     pub fn extract_add_words_w<F>(
@@ -588,9 +776,9 @@ impl KiwiBuilder {
     where
         F: FnMut(i32) -> U16String,
     {
-        let reader_w = Box::into_raw(Box::new(Box::new(reader_w)));
+        let reader_w = Box::into_raw(Box::new(Callback::new(reader_w)));
 
-        let ws = unsafe {
+        let (ws, reader_w) = unsafe {
             let handle = self.handle.lock();
             let ws = kiwi_builder_extract_add_words_w(
                 *handle,
@@ -602,17 +790,23 @@ impl KiwiBuilder {
                 pos_threshold,
             );
 
-            drop(Box::from_raw(reader_w));
-
-            ws
+            (ws, Box::from_raw(reader_w))
         };
 
+        if let Err(err) = reader_w.finish() {
+            if !ws.is_null() {
+                unsafe { kiwi_ws_close(ws) };
+            }
+
+            return Err(err);
+        }
+
         if ws.is_null() {
             let err = kiwi_error().unwrap_or_default();
             return Err(Error::Native(err));
         }
 
-        Ok(Extracted::new(ws))
+        Extracted::new(ws)
     }
 
     /// [Kiwi] 구조체를 생성합니다.
@@ -649,8 +843,7 @@ impl KiwiBuilder {
         }
 
         Ok(Kiwi {
-            #[allow(clippy::arc_with_non_send_sync)]
-            handle: KiwiRc::new(RwLock::new(kiwi)),
+            handle: Arc::new(KiwiHandle::new(kiwi)),
         })
     }
 }
@@ -668,7 +861,8 @@ impl Drop for KiwiBuilder {
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            panic!("KiwiBuilder close error: {}", err);
+            tracing::error!("failed to close `KiwiBuilder`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `KiwiBuilder`");
@@ -0,0 +1,339 @@
+use std::path::{Path, PathBuf};
+
+use either::Either;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DefaultTypoSet, DefaultTypoTransformer, Error, Kiwi, KiwiBuilder, KiwiOptions, POSTag, Result,
+};
+
+/// 설정 파일로부터 [Kiwi]를 생성할 때 사용하는 설정 구조체
+///
+/// TOML 또는 JSON으로 작성하며, 모든 항목은 생략할 수 있습니다.
+/// 알 수 없는 항목이 있으면 에러를 반환합니다.
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::KiwiConfig;
+///
+/// let config = KiwiConfig::from_toml_str(
+///     r#"
+///     num_threads = 2
+///     typo = "basic_typo_set"
+///     typo_cost_threshold = 2.5
+///
+///     [options]
+///     model_type_sbg = true
+///
+///     [[words]]
+///     form = "키위"
+///     tag = "NNP"
+///     score = 1.0
+///
+///     [[pre_analyzed_words]]
+///     form = "사겼다"
+///     analyzed = [
+///         { form = "사귀", tag = "VV" },
+///         { form = "었", tag = "EP" },
+///         { form = "다", tag = "EF" },
+///     ]
+///     score = -3.0
+///
+///     [analyzer]
+///     space_tolerance = 1
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let kiwi = config.build().unwrap();
+///
+/// assert_eq!(kiwi.get_space_tolerance(), 1);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KiwiConfig {
+    /// 모델 폴더의 경로. 설정하지 않으면 내장 모델을 사용합니다.
+    pub model_path: Option<PathBuf>,
+    /// 사용할 스레드의 개수. `0` 또는 `None`으로 설정 시, 코어 개수만큼 스레드 생성함.
+    pub num_threads: Option<u32>,
+    /// [KiwiOptions] 참고
+    pub options: KiwiOptions,
+    /// 불러올 사용자 사전의 경로 목록
+    pub dicts: Vec<PathBuf>,
+    /// [KiwiBuilder::add_word]로 추가할 형태소 목록
+    pub words: Vec<UserWord>,
+    /// [KiwiBuilder::add_alias_word]로 추가할 형태소 목록
+    pub aliases: Vec<AliasWord>,
+    /// [KiwiBuilder::add_pre_analyzed_word]로 추가할 기분석 형태소열 목록
+    pub pre_analyzed_words: Vec<PreAnalyzedWord>,
+    /// 오타 교정에 사용할 기본 오타 정의. 설정하지 않으면 오타를 교정하지 않습니다.
+    pub typo: Option<DefaultTypoSet>,
+    /// 이 값을 넘어가는 비용이 필요한 오타는 교정하지 않습니다. `typo`를 설정한 경우에만 사용할 수 있습니다.
+    pub typo_cost_threshold: Option<f32>,
+    /// [AnalyzerConfig] 참고
+    pub analyzer: AnalyzerConfig,
+}
+
+/// [KiwiBuilder::add_word] 참고
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserWord {
+    pub form: String,
+    pub tag: POSTag,
+    #[serde(default)]
+    pub score: f32,
+}
+
+/// [KiwiBuilder::add_alias_word] 참고
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AliasWord {
+    /// 새 형태소
+    pub form: String,
+    pub tag: POSTag,
+    #[serde(default)]
+    pub score: f32,
+    /// 원본 형태소
+    pub origin: String,
+}
+
+/// [KiwiBuilder::add_pre_analyzed_word] 참고
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PreAnalyzedWord {
+    pub form: String,
+    pub analyzed: Vec<PreAnalyzedMorpheme>,
+    #[serde(default)]
+    pub score: f32,
+}
+
+/// 기분석 형태소열을 이루는 형태소
+///
+/// `begin`, `end`는 형태소가 형태 내에서 차지하는 위치이며,
+/// 한 형태소열 안에서 모든 형태소에 지정하거나 모두 생략해야 합니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PreAnalyzedMorpheme {
+    pub form: String,
+    pub tag: POSTag,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub begin: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<usize>,
+}
+
+/// [Kiwi]를 생성한 뒤 설정할 분석 옵션
+///
+/// 설정하지 않은 옵션은 기본값을 유지합니다. 각 옵션은 [Kiwi]의 같은 이름의 `set_*` 메서드 참고
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AnalyzerConfig {
+    pub integrate_allomorph: Option<bool>,
+    pub max_unk_form_size: Option<u32>,
+    pub space_tolerance: Option<u32>,
+    pub cut_off_threshold: Option<f32>,
+    pub unk_form_score_scale: Option<f32>,
+    pub unk_form_score_bias: Option<f32>,
+    pub space_penalty: Option<f32>,
+}
+
+impl AnalyzerConfig {
+    /// 설정한 옵션들을 [Kiwi]에 적용합니다.
+    pub fn apply(&self, kiwi: &Kiwi) {
+        if let Some(r) = self.integrate_allomorph {
+            kiwi.set_integrate_allomorph(r);
+        }
+        if let Some(r) = self.max_unk_form_size {
+            kiwi.set_max_unk_form_size(r);
+        }
+        if let Some(r) = self.space_tolerance {
+            kiwi.set_space_tolerance(r);
+        }
+        if let Some(r) = self.cut_off_threshold {
+            kiwi.set_cut_off_threshold(r);
+        }
+        if let Some(r) = self.unk_form_score_scale {
+            kiwi.set_unk_form_score_scale(r);
+        }
+        if let Some(r) = self.unk_form_score_bias {
+            kiwi.set_unk_form_score_bias(r);
+        }
+        if let Some(r) = self.space_penalty {
+            kiwi.set_space_penalty(r);
+        }
+    }
+}
+
+impl KiwiConfig {
+    /// TOML 문자열에서 설정을 읽습니다.
+    pub fn from_toml_str(s: &str) -> Result<Self> {
+        toml::from_str(s).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    /// JSON 문자열에서 설정을 읽습니다.
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        serde_json::from_str(s).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    /// 설정 파일을 읽습니다.
+    ///
+    /// 확장자가 `.json`이면 JSON, 그 외에는 TOML로 해석합니다.
+    ///
+    /// `model_path`와 `dicts`에 상대 경로를 적은 경우, 설정 파일이 있는 폴더를 기준으로 합니다.
+    ///
+    /// # Errors
+    ///
+    /// 파일을 읽을 수 없거나 해석에 실패한 경우 [Error::Config]를 반환합니다.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        let s = std::fs::read_to_string(path).map_err(|err| {
+            Error::Config(format!("failed to read `{}`: {}", path.display(), err))
+        })?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+
+        let mut config = if is_json {
+            Self::from_json_str(&s)
+        } else {
+            Self::from_toml_str(&s)
+        }
+        .map_err(|err| Error::Config(format!("`{}`: {}", path.display(), err.message())))?;
+
+        if let Some(base) = path.parent() {
+            config.resolve_paths(base);
+        }
+
+        Ok(config)
+    }
+
+    /// TOML 문자열로 변환합니다.
+    pub fn to_toml_string(&self) -> Result<String> {
+        toml::to_string(self).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    /// JSON 문자열로 변환합니다.
+    pub fn to_json_string(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|err| Error::Config(err.to_string()))
+    }
+
+    fn resolve_paths(&mut self, base: &Path) {
+        if let Some(model_path) = self.model_path.as_mut() {
+            if model_path.is_relative() {
+                *model_path = base.join(&*model_path);
+            }
+        }
+
+        for dict in self.dicts.iter_mut() {
+            if dict.is_relative() {
+                *dict = base.join(&*dict);
+            }
+        }
+    }
+
+    /// 설정대로 [KiwiBuilder]를 생성하고 사전과 사용자 형태소를 추가합니다.
+    ///
+    /// 사용자 사전, 형태소, 변이형, 기분석 형태소열 순서로 추가합니다.
+    pub fn builder(&self) -> Result<KiwiBuilder> {
+        let mut builder = match &self.model_path {
+            Some(model_path) => {
+                KiwiBuilder::with_model_path(model_path, self.num_threads, self.options.clone())?
+            }
+            None => KiwiBuilder::new(self.num_threads, self.options.clone())?,
+        };
+
+        for dict in &self.dicts {
+            let dict = dict.to_str().ok_or_else(|| {
+                Error::InvalidInput(format!("path is not valid UTF-8: {}", dict.display()))
+            })?;
+
+            builder = builder.load_dict(dict)?;
+        }
+
+        for word in &self.words {
+            builder = builder.add_word(&word.form, word.tag, word.score)?;
+        }
+
+        for alias in &self.aliases {
+            builder = builder.add_alias_word(&alias.form, alias.tag, alias.score, &alias.origin)?;
+        }
+
+        for word in &self.pre_analyzed_words {
+            builder = word.add_to(builder)?;
+        }
+
+        Ok(builder)
+    }
+
+    /// 설정대로 [Kiwi]를 생성합니다.
+    ///
+    /// [KiwiConfig::builder]로 생성한 [KiwiBuilder]를 오타 교정 설정과 함께 빌드한 뒤, [AnalyzerConfig]를 적용합니다.
+    ///
+    /// # Errors
+    ///
+    /// `typo` 없이 `typo_cost_threshold`를 설정한 경우 [Error::Config]를 반환합니다.
+    pub fn build(&self) -> Result<Kiwi> {
+        if self.typo.is_none() && self.typo_cost_threshold.is_some() {
+            return Err(Error::Config(
+                "`typo_cost_threshold` requires `typo`".to_owned(),
+            ));
+        }
+
+        let builder = self.builder()?;
+
+        let kiwi = match self.typo {
+            Some(typo) => {
+                let typo = DefaultTypoTransformer::new(typo)?;
+                builder.build(&typo, self.typo_cost_threshold)?
+            }
+            None => builder.build(None, None)?,
+        };
+
+        self.analyzer.apply(&kiwi);
+
+        Ok(kiwi)
+    }
+}
+
+impl PreAnalyzedWord {
+    fn add_to(&self, builder: KiwiBuilder) -> Result<KiwiBuilder> {
+        let positions = self
+            .analyzed
+            .iter()
+            .map(|morph| morph.begin.zip(morph.end))
+            .collect::<Option<Vec<_>>>();
+
+        match positions {
+            Some(positions) => {
+                let analyzed = self
+                    .analyzed
+                    .iter()
+                    .zip(positions)
+                    .map(|(morph, (begin, end))| (morph.form.as_str(), morph.tag, begin, end))
+                    .collect::<Vec<_>>();
+
+                builder.add_pre_analyzed_word(&self.form, Either::Right(&analyzed), self.score)
+            }
+            None if self
+                .analyzed
+                .iter()
+                .all(|morph| morph.begin.is_none() && morph.end.is_none()) =>
+            {
+                let analyzed = self
+                    .analyzed
+                    .iter()
+                    .map(|morph| (morph.form.as_str(), morph.tag))
+                    .collect::<Vec<_>>();
+
+                builder.add_pre_analyzed_word(&self.form, Either::Left(&analyzed), self.score)
+            }
+            None => Err(Error::Config(format!(
+                "`begin` and `end` must be set on all or none of the morphemes of `{}`",
+                self.form
+            ))),
+        }
+    }
+}
@@ -1,5 +1,86 @@
+use std::path::PathBuf;
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// 모델 또는 토크나이저 파일을 불러오는데 실패함
+    #[error("Failed to load model `{}`: {message}", path.display())]
+    ModelLoad { path: PathBuf, message: String },
+
+    /// 알 수 없는 품사 태그
+    #[error("Invalid POS tag: {0}")]
+    InvalidTag(String),
+
+    /// 입력 문자열에 null 문자가 포함되어 있거나 인코딩이 올바르지 않음
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// 사용자 사전을 해석하는데 실패함
+    ///
+    /// `line`은 네이티브 에러 메시지에 줄 번호가 포함된 경우에만 존재합니다.
+    #[error("Failed to parse dictionary `{}`: {message}", path.display())]
+    DictParse {
+        path: PathBuf,
+        line: Option<usize>,
+        message: String,
+    },
+
+    /// 오타 정의를 추가하거나 수정하는데 실패함
+    #[error("Invalid typo definition: {0}")]
+    TypoDefinition(String),
+
+    /// 존재하지 않는 형태소 id 등 범위를 벗어난 값
+    #[error("Out of range: {0}")]
+    OutOfRange(String),
+
+    /// 네이티브 라이브러리에 전달한 콜백에서 panic이 발생함
+    #[error("Callback panicked: {0}")]
+    CallbackPanic(String),
+
+    /// 설정 파일을 읽거나 해석하는데 실패함
+    #[error("Invalid config: {0}")]
+    Config(String),
+
+    /// 분석 작업을 처리하는 스레드가 종료됨
+    #[error("Worker disconnected")]
+    Disconnected,
+
     #[error("Native kiwi error: {0}")]
     Native(String),
 }
+
+impl Error {
+    /// 에러 메시지를 반환합니다.
+    ///
+    /// 네이티브 라이브러리에서 발생한 에러의 경우 `kiwi_error()`가 반환한 메시지입니다.
+    pub fn message(&self) -> &str {
+        match self {
+            Error::ModelLoad { message, .. } | Error::DictParse { message, .. } => message,
+            Error::InvalidTag(message)
+            | Error::InvalidInput(message)
+            | Error::TypoDefinition(message)
+            | Error::OutOfRange(message)
+            | Error::CallbackPanic(message)
+            | Error::Config(message)
+            | Error::Native(message) => message,
+            Error::Disconnected => "worker disconnected",
+        }
+    }
+
+    /// 사용자 사전 에러를 생성합니다.
+    ///
+    /// 네이티브 에러 메시지의 `... at line 3 : ...`처럼 `line` 단어 바로 뒤에 오는 줄 번호를 찾아 함께 저장합니다.
+    pub(crate) fn dict_parse(path: impl Into<PathBuf>, message: String) -> Self {
+        let words = message.split_whitespace().collect::<Vec<_>>();
+
+        let line = words
+            .windows(2)
+            .find(|w| w[0].eq_ignore_ascii_case("line"))
+            .and_then(|w| w[1].trim_end_matches([':', ',']).parse().ok());
+
+        Error::DictParse {
+            path: path.into(),
+            line,
+            message,
+        }
+    }
+}
@@ -2,9 +2,10 @@ use std::{ffi::CStr, fmt::Debug};
 
 use widestring::{U16CStr, U16String};
 
-use crate::{bindings::*, kiwi_error, KiwiRc};
+use crate::{bindings::*, check_float, kiwi_error, Error, KiwiRc, Result};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Word<S>
 where
     S: Debug + Clone,
@@ -26,28 +27,35 @@ unsafe impl Send for Extracted {}
 // unsafe impl Sync for Extracted {}
 
 #[inline]
-fn size(handle: kiwi_ws_h) -> usize {
+fn size(handle: kiwi_ws_h) -> Result<usize> {
     unsafe {
         let size = kiwi_ws_size(handle);
 
         if size < 0 {
-            let err = kiwi_error();
-            panic!("extracted.size() -> {:?}", err);
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
         }
 
-        size as usize
+        Ok(size as usize)
     }
 }
 
 impl Extracted {
-    pub(crate) fn new(handle: kiwi_ws_h) -> Self {
-        let size = size(handle);
+    pub(crate) fn new(handle: kiwi_ws_h) -> Result<Self> {
+        let size = match size(handle) {
+            Ok(size) => size,
+            Err(err) => {
+                // 핸들을 [Extracted]로 감싸기 전에 실패한 경우 직접 해제함
+                unsafe { kiwi_ws_close(handle) };
+                return Err(err);
+            }
+        };
 
-        Self {
+        Ok(Self {
             #[allow(clippy::arc_with_non_send_sync)]
             handle: KiwiRc::new(handle),
             size,
-        }
+        })
     }
 
     #[inline]
@@ -60,114 +68,101 @@ impl Extracted {
         self.size
     }
 
-    pub fn form(&self, index: usize) -> Option<String> {
-        self.check_index(index)?;
-        Some(self.form_unchecked(index))
+    pub fn form(&self, index: usize) -> Result<Option<String>> {
+        self.check_index(index)
+            .map(|_| self.form_unchecked(index))
+            .transpose()
     }
 
     #[inline]
-    fn form_unchecked(&self, index: usize) -> String {
+    fn form_unchecked(&self, index: usize) -> Result<String> {
         unsafe {
             let form = kiwi_ws_form(*self.handle, index as i32);
 
             if form.is_null() {
-                let err = kiwi_error();
-                panic!("extracted.form({}) -> {:?}", index, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let form = CStr::from_ptr(form);
 
-            form.to_owned().into_string().unwrap()
+            Ok(form.to_string_lossy().into_owned())
         }
     }
 
-    pub fn form_w(&self, index: usize) -> Option<U16String> {
-        self.check_index(index)?;
-        Some(self.form_w_unchecked(index))
+    pub fn form_w(&self, index: usize) -> Result<Option<U16String>> {
+        self.check_index(index)
+            .map(|_| self.form_w_unchecked(index))
+            .transpose()
     }
 
     #[inline]
-    fn form_w_unchecked(&self, index: usize) -> U16String {
+    fn form_w_unchecked(&self, index: usize) -> Result<U16String> {
         unsafe {
             let form_w = kiwi_ws_form_w(*self.handle, index as i32);
 
             if form_w.is_null() {
-                let err = kiwi_error();
-                panic!("extracted.form_w({}) -> {:?}", index, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
             let form_w = U16CStr::from_ptr_str(form_w);
 
-            form_w.to_owned().into_ustring()
+            Ok(form_w.to_owned().into_ustring())
         }
     }
 
-    pub fn score(&self, index: usize) -> Option<f32> {
-        self.check_index(index)?;
-        Some(self.score_unchecked(index))
+    pub fn score(&self, index: usize) -> Result<Option<f32>> {
+        self.check_index(index)
+            .map(|_| self.score_unchecked(index))
+            .transpose()
     }
 
     #[inline]
-    fn score_unchecked(&self, index: usize) -> f32 {
-        unsafe {
-            let score = kiwi_ws_score(*self.handle, index as i32);
-
-            if score == 0.0 {
-                let err = kiwi_error();
-                panic!("extracted.score({}) -> {:?}", index, err);
-            }
-
-            score
-        }
+    fn score_unchecked(&self, index: usize) -> Result<f32> {
+        check_float(|| unsafe { kiwi_ws_score(*self.handle, index as i32) })
     }
 
-    pub fn freq(&self, index: usize) -> Option<usize> {
-        self.check_index(index)?;
-        Some(self.freq_unchecked(index))
+    pub fn freq(&self, index: usize) -> Result<Option<usize>> {
+        self.check_index(index)
+            .map(|_| self.freq_unchecked(index))
+            .transpose()
     }
 
     #[inline]
-    fn freq_unchecked(&self, index: usize) -> usize {
+    fn freq_unchecked(&self, index: usize) -> Result<usize> {
         unsafe {
             let freq = kiwi_ws_freq(*self.handle, index as i32);
 
             if freq < 0 {
-                let err = kiwi_error();
-                panic!("extracted.freq({}) -> {:?}", index, err);
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
             }
 
-            freq as usize
+            Ok(freq as usize)
         }
     }
 
-    pub fn pos_score(&self, index: usize) -> Option<f32> {
-        self.check_index(index)?;
-        Some(self.pos_score_unchecked(index))
+    pub fn pos_score(&self, index: usize) -> Result<Option<f32>> {
+        self.check_index(index)
+            .map(|_| self.pos_score_unchecked(index))
+            .transpose()
     }
 
     #[inline]
-    fn pos_score_unchecked(&self, index: usize) -> f32 {
-        unsafe {
-            let pos_score = kiwi_ws_pos_score(*self.handle, index as i32);
-
-            if pos_score == 0.0 {
-                let err = kiwi_error();
-                panic!("extracted.pos_score({}) -> {:?}", index, err);
-            }
-
-            pos_score
-        }
+    fn pos_score_unchecked(&self, index: usize) -> Result<f32> {
+        check_float(|| unsafe { kiwi_ws_pos_score(*self.handle, index as i32) })
     }
 
-    pub fn to_vec(&self) -> Vec<Word<String>> {
+    pub fn to_vec(&self) -> Result<Vec<Word<String>>> {
         let ws_size = self.size();
         let mut words = Vec::with_capacity(ws_size);
 
         for i in 0..ws_size {
-            let form = self.form_unchecked(i);
-            let freq = self.freq_unchecked(i);
-            let score = self.score_unchecked(i);
-            let pos_score = self.pos_score_unchecked(i);
+            let form = self.form_unchecked(i)?;
+            let freq = self.freq_unchecked(i)?;
+            let score = self.score_unchecked(i)?;
+            let pos_score = self.pos_score_unchecked(i)?;
 
             let word_info = Word {
                 form,
@@ -179,18 +174,18 @@ impl Extracted {
             words.push(word_info);
         }
 
-        words
+        Ok(words)
     }
 
-    pub fn to_vec_w(&self) -> Vec<Word<U16String>> {
+    pub fn to_vec_w(&self) -> Result<Vec<Word<U16String>>> {
         let ws_size = self.size();
         let mut words = Vec::with_capacity(ws_size);
 
         for i in 0..ws_size {
-            let form = self.form_w_unchecked(i);
-            let freq = self.freq_unchecked(i);
-            let score = self.score_unchecked(i);
-            let pos_score = self.pos_score_unchecked(i);
+            let form = self.form_w_unchecked(i)?;
+            let freq = self.freq_unchecked(i)?;
+            let score = self.score_unchecked(i)?;
+            let pos_score = self.pos_score_unchecked(i)?;
 
             let word_info = Word {
                 form,
@@ -202,7 +197,7 @@ impl Extracted {
             words.push(word_info);
         }
 
-        words
+        Ok(words)
     }
 
     pub fn iter(&self) -> Iter {
@@ -223,10 +218,9 @@ impl Drop for Extracted {
         let res = unsafe { kiwi_ws_close(*self.handle) };
 
         if res != 0 {
-            panic!(
-                "Extracted close error: {}",
-                kiwi_error().unwrap_or_default()
-            );
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `Extracted`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `Extracted`");
@@ -259,24 +253,24 @@ macro_rules! impl_iterator {
             }
 
             impl Iterator for $struct_name<'_> {
-                type Item = $item_ty;
+                type Item = Result<$item_ty>;
 
                 fn next(&mut self) -> Option<Self::Item> {
                     self.extracted.check_index(self.i)?;
 
-                    let form = self.extracted.$form_fn(self.i);
-                    let freq = self.extracted.freq_unchecked(self.i);
-                    let score = self.extracted.score_unchecked(self.i);
-                    let pos_score = self.extracted.pos_score_unchecked(self.i);
+                    let i = self.i;
+                    let extracted = self.extracted;
 
                     self.i += 1;
 
-                    let item = Word {
-                        form,
-                        freq,
-                        score,
-                        pos_score,
-                    };
+                    let item = (|| -> Result<_> {
+                        Ok(Word {
+                            form: extracted.$form_fn(i)?,
+                            freq: extracted.freq_unchecked(i)?,
+                            score: extracted.score_unchecked(i)?,
+                            pos_score: extracted.pos_score_unchecked(i)?,
+                        })
+                    })();
 
                     Some(item)
                 }
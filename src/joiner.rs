@@ -0,0 +1,142 @@
+use std::ffi::CStr;
+
+use parking_lot::Mutex;
+use widestring::{U16CStr, U16String};
+
+use crate::{bindings::*, kiwi_error, to_cstring, Error, Kiwi, KiwiRc, POSTag, Result};
+
+/// 형태소들을 결합하여 자연스러운 문장으로 복원합니다.
+///
+/// 먹/VV + 었/EP + 다/EF -> 먹었다
+///
+/// [Kiwi::get_integrate_allomorph](crate::Kiwi::get_integrate_allomorph)가 `true`인 경우,
+/// 앞 형태소에 맞춰 음운론적 이형태를 선택하여 결합합니다. 먹/VV + 었/EP -> 먹었, 보/VV + 었/EP -> 봤
+#[derive(Clone)]
+pub struct Joiner {
+    pub(crate) handle: KiwiRc<Mutex<kiwi_joiner_h>>,
+    /// 결합기가 사용하는 [Kiwi]. 먼저 해제되지 않도록 유지하며, 결합하는 동안 읽기 잠금을 잡음
+    kiwi: Kiwi,
+}
+
+#[cfg(feature = "impl_send")]
+unsafe impl Send for Joiner {}
+
+impl Joiner {
+    /// [Joiner]를 생성합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `kiwi` - 형태소 사전과 언어 모델을 제공할 [Kiwi]
+    /// * `lm_search` - `true`로 설정 시 언어 모델을 사용하여 가장 자연스러운 결합 형태를 탐색하고, `false`로 설정 시 규칙에 기반하여 빠르게 결합합니다.
+    pub fn new(kiwi: &Kiwi, lm_search: bool) -> Result<Self> {
+        let handle = unsafe {
+            let kiwi_handle = kiwi.handle.read();
+            kiwi_new_joiner(*kiwi_handle, if lm_search { 1 } else { 0 })
+        };
+
+        if handle.is_null() {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        Ok(Self {
+            #[allow(clippy::arc_with_non_send_sync)]
+            handle: KiwiRc::new(Mutex::new(handle)),
+            kiwi: kiwi.clone(),
+        })
+    }
+
+    /// 결합할 형태소를 추가합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `form` - 형태소의 형태 (utf-8)
+    /// * `tag` - 형태소의 품사 태그
+    /// * `infer_regularity` - `true`로 설정 시 동/형용사의 불규칙 활용 여부를 사전에서 찾아 자동으로 추론하고, `false`로 설정 시 `tag`가 불규칙 활용 태그(예: [POSTag::VVI])인지에 따라 결정합니다.
+    pub fn add(&self, form: &str, tag: POSTag, infer_regularity: bool) -> Result<()> {
+        let form = to_cstring(form)?;
+        let tag = to_cstring(tag.as_str())?;
+
+        // 결합기는 Kiwi의 사전과 언어 모델을 사용하므로, 옵션 설정과 겹치지 않도록 읽기 잠금을 함께 잡음
+        let res = unsafe {
+            let _kiwi_handle = self.kiwi.handle.read();
+            let handle = self.handle.lock();
+            kiwi_joiner_add(
+                *handle,
+                form.as_ptr(),
+                tag.as_ptr(),
+                if infer_regularity { 1 } else { 0 },
+            )
+        };
+
+        if res != 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        Ok(())
+    }
+
+    /// 지금까지 추가된 형태소들을 결합한 결과를 반환합니다.
+    ///
+    /// # Return
+    /// UTF-8 인코딩된 문자열
+    pub fn get(&self) -> Result<String> {
+        unsafe {
+            let _kiwi_handle = self.kiwi.handle.read();
+            let handle = self.handle.lock();
+            let res = kiwi_joiner_get(*handle);
+
+            if res.is_null() {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
+            }
+
+            let res = CStr::from_ptr(res);
+
+            Ok(res.to_string_lossy().into_owned())
+        }
+    }
+
+    /// 지금까지 추가된 형태소들을 결합한 결과를 반환합니다.
+    ///
+    /// # Return
+    /// UTF-16 인코딩된 문자열
+    pub fn get_w(&self) -> Result<U16String> {
+        unsafe {
+            let _kiwi_handle = self.kiwi.handle.read();
+            let handle = self.handle.lock();
+            let res = kiwi_joiner_get_w(*handle);
+
+            if res.is_null() {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
+            }
+
+            let res = U16CStr::from_ptr_str(res);
+
+            Ok(res.to_owned().into_ustring())
+        }
+    }
+}
+
+impl Drop for Joiner {
+    fn drop(&mut self) {
+        if KiwiRc::strong_count(&self.handle) > 1 {
+            return;
+        }
+
+        let res = unsafe {
+            let handle = self.handle.lock();
+            kiwi_joiner_close(*handle)
+        };
+
+        if res != 0 {
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `Joiner`: {}", err);
+            return;
+        }
+
+        tracing::trace!("closed `Joiner`");
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{analyzed::Token, POSTag};
+
+/// 텍스트에서 추출한 명사 또는 키워드
+///
+/// [Kiwi::extract_nouns](crate::Kiwi::extract_nouns), [Kiwi::keywords](crate::Kiwi::keywords) 참고
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyword {
+    /// 형태
+    ///
+    /// 복합 명사로 병합한 경우 구성 형태소들의 형태를 이어붙인 값입니다.
+    pub form: String,
+    /// 품사 태그
+    ///
+    /// 복합 명사로 병합한 경우 구성 형태소 중 하나라도 [POSTag::NNP]이면 [POSTag::NNP], 아니면 [POSTag::NNG]입니다.
+    pub tag: POSTag,
+    /// 등장 횟수
+    pub count: usize,
+    /// 등장한 위치의 목록 (UTF-8 바이트 기준 시작, 끝 위치)
+    pub spans: Vec<(usize, usize)>,
+}
+
+/// 명사 또는 키워드 추출에서 제외할 (형태, 품사 태그) 목록
+///
+/// [MorphemeSet](crate::MorphemeSet)과 달리 사전에 없는 형태소도 추가할 수 있으므로,
+/// SL, SH 형태소나 [POSTag::W_HASHTAG], [POSTag::W_URL] 등 분석 중에 만들어지는 형태소도 제외할 수 있습니다.
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::{POSTag, Stopwords};
+///
+/// let stopwords = Stopwords::from_iter([("추천", POSTag::NNG), ("#광고", POSTag::W_HASHTAG)]);
+///
+/// assert!(stopwords.contains("#광고", POSTag::W_HASHTAG));
+/// assert!(!stopwords.contains("#광고", POSTag::NNG));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stopwords {
+    entries: HashSet<(String, POSTag)>,
+}
+
+impl Stopwords {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 형태소를 추가합니다. 이미 포함된 형태소인 경우 `false`를 반환합니다.
+    pub fn add(&mut self, form: impl Into<String>, tag: POSTag) -> bool {
+        self.entries.insert((form.into(), tag))
+    }
+
+    /// 포함된 형태소인지 확인합니다.
+    pub fn contains(&self, form: &str, tag: POSTag) -> bool {
+        self.entries.contains(&(form.to_owned(), tag))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<S> FromIterator<(S, POSTag)> for Stopwords
+where
+    S: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (S, POSTag)>>(iter: I) -> Self {
+        Self {
+            entries: iter
+                .into_iter()
+                .map(|(form, tag)| (form.into(), tag))
+                .collect(),
+        }
+    }
+}
+
+impl<S> Extend<(S, POSTag)> for Stopwords
+where
+    S: Into<String>,
+{
+    fn extend<I: IntoIterator<Item = (S, POSTag)>>(&mut self, iter: I) {
+        self.entries
+            .extend(iter.into_iter().map(|(form, tag)| (form.into(), tag)));
+    }
+}
+
+/// 명사로 취급하는 품사 태그인지 확인합니다.
+///
+/// W_로 시작하는 태그는 [Match](crate::Match)에서 해당 옵션을 켠 경우에만 분석 결과에 나타납니다.
+#[inline]
+fn is_keyword_tag(tag: POSTag) -> bool {
+    matches!(
+        tag,
+        POSTag::NNG | POSTag::NNP | POSTag::NNB | POSTag::SL | POSTag::SH
+    ) || tag.is_web()
+}
+
+#[inline]
+fn is_compound_part(tag: POSTag) -> bool {
+    matches!(tag, POSTag::NNG | POSTag::NNP)
+}
+
+/// 형태소 목록에서 명사를 추출하고 (형태, 품사 태그)별로 묶습니다.
+///
+/// 처음 등장한 순서대로 정렬된 목록을 반환합니다.
+pub(crate) fn extract_keywords<S>(
+    tokens: &[(S, Token)],
+    merge_compound: bool,
+    stopwords: Option<&Stopwords>,
+) -> Vec<Keyword>
+where
+    S: AsRef<str>,
+{
+    let mut keywords: Vec<Keyword> = Vec::new();
+    let mut indices: HashMap<(String, POSTag), usize> = HashMap::new();
+
+    let mut push = |form: String, tag: POSTag, span: (usize, usize)| {
+        if stopwords.is_some_and(|stopwords| stopwords.contains(&form, tag)) {
+            return;
+        }
+
+        match indices.get(&(form.clone(), tag)) {
+            Some(&i) => {
+                keywords[i].count += 1;
+                keywords[i].spans.push(span);
+            }
+            None => {
+                indices.insert((form.clone(), tag), keywords.len());
+                keywords.push(Keyword {
+                    form,
+                    tag,
+                    count: 1,
+                    spans: vec![span],
+                });
+            }
+        }
+    };
+
+    // 병합 중인 복합 명사 (형태, 품사 태그, 시작 위치, 끝 위치)
+    let mut pending: Option<(String, POSTag, usize, usize)> = None;
+
+    for (form, token) in tokens {
+        let form = form.as_ref();
+
+        // 불용어는 복합 명사에도 포함하지 않음
+        if !is_keyword_tag(token.tag)
+            || stopwords.is_some_and(|stopwords| stopwords.contains(form, token.tag))
+        {
+            if let Some((form, tag, begin, end)) = pending.take() {
+                push(form, tag, (begin, end));
+            }
+            continue;
+        }
+
+        if merge_compound && is_compound_part(token.tag) {
+            if let Some((pending_form, pending_tag, _, end)) = pending.as_mut() {
+                if is_compound_part(*pending_tag) && *end == token.byte_begin {
+                    pending_form.push_str(form);
+                    if token.tag == POSTag::NNP {
+                        *pending_tag = POSTag::NNP;
+                    }
+                    *end = token.byte_end;
+                    continue;
+                }
+            }
+        }
+
+        if let Some((form, tag, begin, end)) = pending.take() {
+            push(form, tag, (begin, end));
+        }
+
+        pending = Some((form.to_owned(), token.tag, token.byte_begin, token.byte_end));
+    }
+
+    if let Some((form, tag, begin, end)) = pending.take() {
+        push(form, tag, (begin, end));
+    }
+
+    keywords
+}
@@ -1,20 +1,83 @@
-use std::{ffi::CString, str::FromStr};
+use std::{
+    cmp::Reverse,
+    ffi::{c_char, c_uint, c_void, CStr},
+    ops::Deref,
+    sync::Arc,
+};
 
 use parking_lot::RwLock;
-use widestring::{U16CString, U16Str};
+use widestring::{U16Str, U16String};
 
 use crate::{
-    bindings::*, kiwi_error, Analyzed, Error, KiwiRc, Match, MorphemeSet, Pretokenized, Result,
+    bindings::*,
+    keyword::extract_keywords,
+    kiwi_error, text_to_cstring, text_to_u16cstring, to_cstring,
+    trampoline::{
+        multi_reader_trampoline, multi_reader_w_trampoline, multi_receiver_trampoline, Callback,
+        MultiAnalyze,
+    },
+    Analyzed, Error, Joiner, Keyword, Match, Morpheme, MorphemeSet, POSTag, Pretokenized, Result,
+    Sentences, Stopwords,
 };
 
+/// 형태소 분석기
+///
+/// [KiwiBuilder::build](crate::KiwiBuilder::build)로 생성합니다.
+///
+/// [Kiwi]는 [Send] + [Sync]이며, 복제([Clone])하면 같은 분석기를 공유합니다.
+/// 여러 스레드에서 동시에 [Kiwi::analyze] 등의 분석 메서드를 호출할 수 있고,
+/// [Kiwi::set_space_penalty] 같은 옵션 설정 메서드는 진행 중인 분석이 끝난 후 순차적으로 적용됩니다.
+///
+/// # Example
+///
+/// ```rust
+/// use std::thread;
+///
+/// use rkiwi::{KiwiBuilder, Match};
+///
+/// let kiwi = KiwiBuilder::new(None, Default::default())
+///     .unwrap()
+///     .build(None, None)
+///     .unwrap();
+///
+/// thread::scope(|s| {
+///     for text in ["안녕하세요.", "만둣국 먹고 싶다."] {
+///         let kiwi = &kiwi;
+///         s.spawn(move || {
+///             let analyzed = kiwi.analyze(text, 1, Match::new(), None, None).unwrap();
+///             analyzed.to_result().unwrap()
+///         });
+///     }
+/// });
+/// ```
 #[derive(Clone)]
 pub struct Kiwi {
-    pub(crate) handle: KiwiRc<RwLock<kiwi_h>>,
+    pub(crate) handle: Arc<KiwiHandle>,
 }
 
-#[cfg(feature = "impl_send")]
-unsafe impl Send for Kiwi {}
-// unsafe impl Sync for Kiwi {}
+/// 네이티브 분석기 핸들
+///
+/// 마지막 [Kiwi]가 해제될 때 한 번만 닫히도록, 닫는 작업은 [Arc] 내부의 이 구조체가 담당함
+pub(crate) struct KiwiHandle(RwLock<kiwi_h>);
+
+// 빌드가 끝난 네이티브 분석기는 분석 중에 읽기만 하므로 여러 스레드에서 동시에 읽기 잠금을 잡고 분석해도 안전하며,
+// 분석기의 상태를 바꾸는 옵션 설정은 쓰기 잠금을 잡고 수행함
+unsafe impl Send for KiwiHandle {}
+unsafe impl Sync for KiwiHandle {}
+
+impl KiwiHandle {
+    pub(crate) fn new(handle: kiwi_h) -> Self {
+        Self(RwLock::new(handle))
+    }
+}
+
+impl Deref for KiwiHandle {
+    type Target = RwLock<kiwi_h>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 impl Kiwi {
     // pub(crate) fn new<P>(
@@ -154,7 +217,7 @@ impl Kiwi {
     /// 텍스트를 분석해 형태소 결과를 반환합니다.
     ///
     /// # Parameters
-    /// * `text` - 분석할 텍스트 (utf-8)
+    /// * `text` - 분석할 텍스트 (utf-8). null 문자는 공백으로 취급되며, 위치 정보는 원본 텍스트를 기준으로 합니다.
     /// * `top_n` - 분석 결과 후보를 상위 몇개까지 생성할지 설정합니다.
     /// * `match_options` - [Match] 참고
     /// * `blocklist` - 분석 후보 탐색 과정에서 blocklist에 포함된 형태소들은 배제됩니다.
@@ -173,7 +236,7 @@ impl Kiwi {
         let blocklist: Option<&MorphemeSet> = blocklist.into();
         let pretokenized: Option<&Pretokenized> = pretokenized.into();
 
-        let text = CString::from_str(text).unwrap();
+        let c_text = text_to_cstring(text);
 
         let res = unsafe {
             let blocklist = blocklist.map(|x| x.handle.lock());
@@ -189,7 +252,7 @@ impl Kiwi {
             let handle = self.handle.read();
             kiwi_analyze(
                 *handle,
-                text.as_ptr(),
+                c_text.as_ptr(),
                 top_n,
                 match_options.finish(),
                 blocklist,
@@ -202,13 +265,13 @@ impl Kiwi {
             return Err(Error::Native(err));
         }
 
-        Ok(Analyzed::new(res))
+        Analyzed::new(res, self, text.into())
     }
 
     /// 텍스트를 분석해 형태소 결과를 반환합니다.
     ///
     /// # Parameters
-    /// * `text` - 분석할 텍스트 (utf-16)
+    /// * `text` - 분석할 텍스트 (utf-16). null 문자는 공백으로 취급됩니다.
     /// * `top_n` - 분석 결과 후보를 상위 몇개까지 생성할지 설정합니다.
     /// * `match_options` - [Match] 참고
     /// * `blocklist` - 분석 후보 탐색 과정에서 blocklist에 포함된 형태소들은 배제됩니다.
@@ -227,7 +290,8 @@ impl Kiwi {
         let blocklist: Option<&MorphemeSet> = blocklist.into();
         let pretokenized: Option<&Pretokenized> = pretokenized.into();
 
-        let text = U16CString::from_ustr(text).unwrap();
+        let text = text.as_ref();
+        let c_text = text_to_u16cstring(text);
 
         let res = unsafe {
             let blocklist = blocklist.map(|x| x.handle.lock());
@@ -243,7 +307,7 @@ impl Kiwi {
             let handle = self.handle.read();
             kiwi_analyze_w(
                 *handle,
-                text.as_ptr(),
+                c_text.as_ptr(),
                 top_n,
                 match_options.finish(),
                 blocklist,
@@ -256,24 +320,609 @@ impl Kiwi {
             return Err(Error::Native(err));
         }
 
-        Ok(Analyzed::new(res))
+        Analyzed::new(res, self, text.into())
     }
-}
 
-impl Drop for Kiwi {
-    fn drop(&mut self) {
-        if KiwiRc::strong_count(&self.handle) > 1 {
-            return;
+    /// 여러 텍스트를 한 번에 분석합니다.
+    ///
+    /// [KiwiBuilder::new](crate::KiwiBuilder::new)에서 설정한 스레드 풀을 이용해 텍스트들을 병렬로 분석합니다.
+    ///
+    /// # Parameters
+    /// * `texts` - 분석할 텍스트 목록 (utf-8)
+    /// * `top_n` - 분석 결과 후보를 상위 몇개까지 생성할지 설정합니다.
+    /// * `match_options` - [Match] 참고
+    /// * `blocklist` - 분석 후보 탐색 과정에서 blocklist에 포함된 형태소들은 배제됩니다.
+    ///
+    /// # Return
+    /// (입력 텍스트의 index, [Analyzed])의 리스트를 입력 순서대로 반환합니다.
+    pub fn analyze_many<'a>(
+        &self,
+        texts: impl IntoIterator<Item = impl AsRef<str>>,
+        top_n: i32,
+        match_options: Match,
+        blocklist: impl Into<Option<&'a MorphemeSet>>,
+    ) -> Result<Vec<(usize, Analyzed)>> {
+        let blocklist: Option<&MorphemeSet> = blocklist.into();
+
+        let texts = texts
+            .into_iter()
+            .map(|text| text.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        // reader가 빈 문자열을 반환하면 입력이 끝난 것으로 처리되므로, 빈 텍스트는 따로 분석함
+        let (indices, non_empty): (Vec<_>, Vec<_>) = texts
+            .iter()
+            .enumerate()
+            .filter(|(_, text)| !text.is_empty())
+            .unzip();
+
+        let mut results = texts.iter().map(|_| None).collect::<Vec<_>>();
+
+        self.analyze_m(
+            |idx| {
+                non_empty
+                    .get(idx as usize)
+                    .map(|text| text.to_string())
+                    .unwrap_or_default()
+            },
+            // 전달받은 결과의 소유권은 receiver에게 있으므로 Analyzed가 해제를 담당함
+            |idx, res| {
+                results[indices[idx]] =
+                    Some(Analyzed::new(res, self, non_empty[idx].as_str().into()))
+            },
+            top_n,
+            match_options,
+            blocklist,
+        )?;
+
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(i, analyzed)| match analyzed {
+                Some(analyzed) => analyzed.map(|analyzed| (i, analyzed)),
+                None => self
+                    .analyze(&texts[i], top_n, match_options, blocklist, None)
+                    .map(|analyzed| (i, analyzed)),
+            })
+            .collect()
+    }
+
+    /// 여러 텍스트를 한 번에 분석합니다.
+    ///
+    /// [KiwiBuilder::new](crate::KiwiBuilder::new)에서 설정한 스레드 풀을 이용해 텍스트들을 병렬로 분석합니다.
+    ///
+    /// # Parameters
+    /// * `texts` - 분석할 텍스트 목록 (utf-16)
+    /// * `top_n` - 분석 결과 후보를 상위 몇개까지 생성할지 설정합니다.
+    /// * `match_options` - [Match] 참고
+    /// * `blocklist` - 분석 후보 탐색 과정에서 blocklist에 포함된 형태소들은 배제됩니다.
+    ///
+    /// # Return
+    /// (입력 텍스트의 index, [Analyzed])의 리스트를 입력 순서대로 반환합니다.
+    pub fn analyze_many_w<'a>(
+        &self,
+        texts: impl IntoIterator<Item = impl AsRef<U16Str>>,
+        top_n: i32,
+        match_options: Match,
+        blocklist: impl Into<Option<&'a MorphemeSet>>,
+    ) -> Result<Vec<(usize, Analyzed)>> {
+        let blocklist: Option<&MorphemeSet> = blocklist.into();
+
+        let texts = texts
+            .into_iter()
+            .map(|text| text.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        // reader가 빈 문자열을 반환하면 입력이 끝난 것으로 처리되므로, 빈 텍스트는 따로 분석함
+        let (indices, non_empty): (Vec<_>, Vec<_>) = texts
+            .iter()
+            .enumerate()
+            .filter(|(_, text)| !text.is_empty())
+            .unzip();
+
+        let mut results = texts.iter().map(|_| None).collect::<Vec<_>>();
+
+        self.analyze_mw(
+            |idx| {
+                non_empty
+                    .get(idx as usize)
+                    .map(|text| text.to_ustring())
+                    .unwrap_or_default()
+            },
+            // 전달받은 결과의 소유권은 receiver에게 있으므로 Analyzed가 해제를 담당함
+            |idx, res| {
+                results[indices[idx]] =
+                    Some(Analyzed::new(res, self, non_empty[idx].as_ustr().into()))
+            },
+            top_n,
+            match_options,
+            blocklist,
+        )?;
+
+        results
+            .into_iter()
+            .enumerate()
+            .map(|(i, analyzed)| match analyzed {
+                Some(analyzed) => analyzed.map(|analyzed| (i, analyzed)),
+                None => self
+                    .analyze_w(&texts[i], top_n, match_options, blocklist, None)
+                    .map(|analyzed| (i, analyzed)),
+            })
+            .collect()
+    }
+
+    fn analyze_m<R, C>(
+        &self,
+        reader: R,
+        receiver: C,
+        top_n: i32,
+        match_options: Match,
+        blocklist: Option<&MorphemeSet>,
+    ) -> Result<usize>
+    where
+        R: FnMut(i32) -> String,
+        C: FnMut(usize, kiwi_res_h),
+    {
+        let multi = Box::into_raw(Box::new(Callback::new(MultiAnalyze { reader, receiver })));
+
+        let (res, multi) = unsafe {
+            let blocklist = blocklist.map(|x| x.handle.lock());
+            let blocklist = match blocklist.as_ref() {
+                Some(blocklist) => **blocklist,
+                None => std::ptr::null::<kiwi_morphset>() as *mut _,
+            };
+            let handle = self.handle.read();
+            let res = kiwi_analyze_m(
+                *handle,
+                Some(multi_reader_trampoline::<R, C>),
+                Some(multi_receiver_trampoline::<R, C>),
+                multi as *mut c_void,
+                top_n,
+                match_options.finish(),
+                blocklist,
+            );
+
+            (res, Box::from_raw(multi))
+        };
+
+        multi.finish()?;
+
+        if res < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
         }
 
-        let res = unsafe {
+        Ok(res as usize)
+    }
+
+    fn analyze_mw<R, C>(
+        &self,
+        reader_w: R,
+        receiver: C,
+        top_n: i32,
+        match_options: Match,
+        blocklist: Option<&MorphemeSet>,
+    ) -> Result<usize>
+    where
+        R: FnMut(i32) -> U16String,
+        C: FnMut(usize, kiwi_res_h),
+    {
+        let multi = Box::into_raw(Box::new(Callback::new(MultiAnalyze {
+            reader: reader_w,
+            receiver,
+        })));
+
+        let (res, multi) = unsafe {
+            let blocklist = blocklist.map(|x| x.handle.lock());
+            let blocklist = match blocklist.as_ref() {
+                Some(blocklist) => **blocklist,
+                None => std::ptr::null::<kiwi_morphset>() as *mut _,
+            };
+            let handle = self.handle.read();
+            let res = kiwi_analyze_mw(
+                *handle,
+                Some(multi_reader_w_trampoline::<R, C>),
+                Some(multi_receiver_trampoline::<R, C>),
+                multi as *mut c_void,
+                top_n,
+                match_options.finish(),
+                blocklist,
+            );
+
+            (res, Box::from_raw(multi))
+        };
+
+        multi.finish()?;
+
+        if res < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        Ok(res as usize)
+    }
+
+    /// 사전에서 형태가 일치하는 형태소들을 찾습니다.
+    ///
+    /// # Parameters
+    /// * `form` - 찾을 형태 (utf-8)
+    /// * `tag` - 품사 태그. `None`으로 설정 시 품사에 상관 없이 찾습니다.
+    /// * `sense_id` - 의미 번호. `None`으로 설정 시 의미 번호에 상관 없이 찾습니다.
+    ///
+    /// # Return
+    /// 일치하는 형태소가 없는 경우 빈 리스트를 반환합니다. [Morpheme] 참고
+    pub fn find_morphemes(
+        &self,
+        form: &str,
+        tag: impl Into<Option<POSTag>>,
+        sense_id: impl Into<Option<u8>>,
+    ) -> Result<Vec<Morpheme>> {
+        let form = to_cstring(form)?;
+
+        let morph_ids = self.find_morpheme_ids(
+            tag.into(),
+            sense_id.into(),
+            None,
+            |handle, tag, sense_id, buf, max_count| unsafe {
+                kiwi_find_morphemes(handle, form.as_ptr(), tag, sense_id, buf, max_count)
+            },
+        )?;
+
+        morph_ids.into_iter().map(|id| self.morpheme(id)).collect()
+    }
+
+    /// 사전에서 형태가 주어진 접두사로 시작하는 형태소들을 찾습니다.
+    ///
+    /// # Parameters
+    /// * `prefix` - 찾을 형태의 접두사 (utf-8)
+    /// * `tag` - 품사 태그. `None`으로 설정 시 품사에 상관 없이 찾습니다.
+    /// * `sense_id` - 의미 번호. `None`으로 설정 시 의미 번호에 상관 없이 찾습니다.
+    /// * `max_count` - 최대 몇개까지 찾을지 설정합니다.
+    ///
+    /// # Return
+    /// 일치하는 형태소가 없는 경우 빈 리스트를 반환합니다. [Morpheme] 참고
+    pub fn find_morphemes_with_prefix(
+        &self,
+        prefix: &str,
+        tag: impl Into<Option<POSTag>>,
+        sense_id: impl Into<Option<u8>>,
+        max_count: usize,
+    ) -> Result<Vec<Morpheme>> {
+        let prefix = to_cstring(prefix)?;
+
+        let morph_ids = self.find_morpheme_ids(
+            tag.into(),
+            sense_id.into(),
+            Some(max_count),
+            |handle, tag, sense_id, buf, max_count| unsafe {
+                kiwi_find_morphemes_with_prefix(
+                    handle,
+                    prefix.as_ptr(),
+                    tag,
+                    sense_id,
+                    buf,
+                    max_count,
+                )
+            },
+        )?;
+
+        morph_ids.into_iter().map(|id| self.morpheme(id)).collect()
+    }
+
+    /// `max_count`가 `None`인 경우, 찾은 개수가 버퍼 크기보다 작아질 때까지 버퍼를 늘려가며 다시 찾습니다.
+    fn find_morpheme_ids<F>(
+        &self,
+        tag: Option<POSTag>,
+        sense_id: Option<u8>,
+        max_count: Option<usize>,
+        find: F,
+    ) -> Result<Vec<u32>>
+    where
+        F: Fn(kiwi_h, *const c_char, i32, *mut c_uint, i32) -> i32,
+    {
+        let tag = tag.map(|tag| to_cstring(tag.as_str())).transpose()?;
+        let sense_id = sense_id.map(|x| x as i32).unwrap_or(-1);
+
+        let mut buf = vec![0 as c_uint; max_count.unwrap_or(16)];
+
+        loop {
+            let res = {
+                let handle = self.handle.read();
+                find(
+                    *handle,
+                    tag.as_ref().map(|x| x.as_ptr()).unwrap_or(std::ptr::null()),
+                    sense_id,
+                    buf.as_mut_ptr(),
+                    buf.len() as i32,
+                )
+            };
+
+            if res < 0 {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
+            }
+
+            let res = res as usize;
+
+            if max_count.is_none() && res >= buf.len() {
+                buf.resize(buf.len() * 2, 0);
+                continue;
+            }
+
+            buf.truncate(res);
+
+            return Ok(buf);
+        }
+    }
+
+    /// 형태소 id에 해당하는 형태소의 정보를 반환합니다.
+    ///
+    /// # Parameters
+    /// * `morph_id` - 형태소 id. [Kiwi::find_morphemes] 참고
+    ///
+    /// # Errors
+    ///
+    /// 존재하지 않는 형태소 id인 경우 에러를 반환합니다.
+    pub fn morpheme(&self, morph_id: u32) -> Result<Morpheme> {
+        let handle = self.handle.read();
+
+        let info = unsafe { kiwi_get_morpheme_info(*handle, morph_id) };
+
+        if info.is_null() {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::OutOfRange(err));
+        }
+
+        let kiwi_morpheme_t {
+            tag,
+            sense_id,
+            user_score,
+            lm_morpheme_id,
+            orig_morpheme_id,
+            ..
+        } = unsafe { *info };
+
+        let form = unsafe {
+            let form = kiwi_get_morpheme_form(*handle, morph_id);
+
+            if form.is_null() {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
+            }
+
+            let r = CStr::from_ptr(form).to_string_lossy().into_owned();
+
+            kiwi_free_morpheme_form(form);
+
+            r
+        };
+
+        Ok(Morpheme {
+            id: morph_id,
+            form,
+            tag: POSTag(tag),
+            sense_id,
+            user_score,
+            lm_morpheme_id,
+            orig_morpheme_id,
+        })
+    }
+
+    /// 텍스트를 문장 단위로 분할합니다.
+    ///
+    /// # Parameters
+    /// * `text` - 분할할 텍스트 (utf-8). null 문자는 공백으로 취급됩니다.
+    /// * `match_options` - [Match] 참고
+    /// * `with_analyzed` - `true`로 설정 시 분할 과정에서 생성된 형태소 분석 결과를 함께 반환합니다.
+    ///
+    /// # Return
+    /// [Sentences] 참고. 각 문장의 시작/끝 위치는 UTF-8 문자열의 바이트 단위입니다.
+    pub fn split_into_sents(
+        &self,
+        text: &str,
+        match_options: Match,
+        with_analyzed: bool,
+    ) -> Result<Sentences> {
+        let c_text = text_to_cstring(text);
+
+        let mut res = std::ptr::null_mut::<kiwi_res>();
+
+        let ss = unsafe {
+            let handle = self.handle.read();
+            kiwi_split_into_sents(
+                *handle,
+                c_text.as_ptr(),
+                match_options.finish(),
+                if with_analyzed {
+                    &mut res
+                } else {
+                    std::ptr::null_mut()
+                },
+            )
+        };
+
+        if ss.is_null() {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        let analyzed = match (!res.is_null())
+            .then(|| Analyzed::new(res, self, text.into()))
+            .transpose()
+        {
+            Ok(analyzed) => analyzed,
+            Err(err) => {
+                unsafe { kiwi_ss_close(ss) };
+                return Err(err);
+            }
+        };
+
+        Sentences::new(ss, analyzed)
+    }
+
+    /// 텍스트를 문장 단위로 분할합니다.
+    ///
+    /// # Parameters
+    /// * `text` - 분할할 텍스트 (utf-16). null 문자는 공백으로 취급됩니다.
+    /// * `match_options` - [Match] 참고
+    /// * `with_analyzed` - `true`로 설정 시 분할 과정에서 생성된 형태소 분석 결과를 함께 반환합니다.
+    ///
+    /// # Return
+    /// [Sentences] 참고. 각 문장의 시작/끝 위치는 UTF-16 문자열의 글자 단위입니다.
+    pub fn split_into_sents_w(
+        &self,
+        text: impl AsRef<U16Str>,
+        match_options: Match,
+        with_analyzed: bool,
+    ) -> Result<Sentences> {
+        let text = text.as_ref();
+        let c_text = text_to_u16cstring(text);
+
+        let mut res = std::ptr::null_mut::<kiwi_res>();
+
+        let ss = unsafe {
             let handle = self.handle.read();
+            kiwi_split_into_sents_w(
+                *handle,
+                c_text.as_ptr(),
+                match_options.finish(),
+                if with_analyzed {
+                    &mut res
+                } else {
+                    std::ptr::null_mut()
+                },
+            )
+        };
+
+        if ss.is_null() {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        let analyzed = match (!res.is_null())
+            .then(|| Analyzed::new(res, self, text.into()))
+            .transpose()
+        {
+            Ok(analyzed) => analyzed,
+            Err(err) => {
+                unsafe { kiwi_ss_close(ss) };
+                return Err(err);
+            }
+        };
+
+        Sentences::new(ss, analyzed)
+    }
+
+    /// 형태소들을 결합하여 문장으로 복원합니다.
+    ///
+    /// 언어 모델로 결합 형태를 탐색하고, 불규칙 활용 여부는 사전에서 추론합니다.
+    /// 이 동작을 조정하거나 결과를 UTF-16으로 받으려면 [Joiner]를 직접 사용해 주세요.
+    ///
+    /// # Parameters
+    /// * `morphs` - 결합할 형태소의 (형태, 품사 태그) 목록
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rkiwi::{KiwiBuilder, POSTag};
+    ///
+    /// let kiwi = KiwiBuilder::new(None, Default::default())
+    ///     .unwrap()
+    ///     .build(None, None)
+    ///     .unwrap();
+    ///
+    /// let joined = kiwi
+    ///     .join([("먹", POSTag::VV), ("었", POSTag::EP), ("다", POSTag::EF)])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(joined, "먹었다");
+    /// ```
+    pub fn join<'a>(&self, morphs: impl IntoIterator<Item = (&'a str, POSTag)>) -> Result<String> {
+        let joiner = Joiner::new(self, true)?;
+
+        for (form, tag) in morphs {
+            joiner.add(form, tag, true)?;
+        }
+
+        joiner.get()
+    }
+
+    /// 텍스트에서 명사를 추출합니다.
+    ///
+    /// NNG, NNP, NNB, SL, SH 태그의 형태소와, `match_options`에서 켠 URL, 이메일, 해시태그 등의 W_ 태그 형태소를 추출합니다.
+    ///
+    /// # Parameters
+    /// * `text` - 분석할 텍스트 (utf-8)
+    /// * `match_options` - [Match] 참고
+    /// * `merge_compound` - `true`로 설정 시 공백 없이 연속된 NNG, NNP 형태소를 하나의 복합 명사로 병합합니다.
+    /// * `stopwords` - 포함된 (형태, 품사 태그)의 형태소는 결과에서 제외되며, 복합 명사에도 병합되지 않습니다. [Stopwords] 참고
+    ///
+    /// # Return
+    /// (형태, 품사 태그)별로 묶인 [Keyword]의 목록. 처음 등장한 순서대로 정렬됩니다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rkiwi::{KiwiBuilder, Match};
+    ///
+    /// let kiwi = KiwiBuilder::new(None, Default::default())
+    ///     .unwrap()
+    ///     .build(None, None)
+    ///     .unwrap();
+    ///
+    /// let nouns = kiwi
+    ///     .extract_nouns("형태소 분석기로 형태소를 분석합니다.", Match::new(), false, None)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(nouns[0].form, "형태소");
+    /// assert_eq!(nouns[0].count, 2);
+    /// ```
+    pub fn extract_nouns<'a>(
+        &self,
+        text: &str,
+        match_options: Match,
+        merge_compound: bool,
+        stopwords: impl Into<Option<&'a Stopwords>>,
+    ) -> Result<Vec<Keyword>> {
+        let analyzed = self.analyze(text, 1, match_options, None, None)?;
+        let tokens = analyzed.to_vec()?;
+
+        Ok(extract_keywords(&tokens, merge_compound, stopwords.into()))
+    }
+
+    /// 텍스트에서 많이 등장한 명사를 상위 `top_k`개까지 반환합니다.
+    ///
+    /// 등장 횟수가 같은 경우 먼저 등장한 명사가 앞에 옵니다.
+    /// 나머지 매개변수는 [Kiwi::extract_nouns]와 같습니다.
+    pub fn keywords<'a>(
+        &self,
+        text: &str,
+        top_k: usize,
+        match_options: Match,
+        merge_compound: bool,
+        stopwords: impl Into<Option<&'a Stopwords>>,
+    ) -> Result<Vec<Keyword>> {
+        let mut keywords = self.extract_nouns(text, match_options, merge_compound, stopwords)?;
+
+        // 안정 정렬이므로 등장 횟수가 같으면 처음 등장한 순서가 유지됨
+        keywords.sort_by_key(|keyword| Reverse(keyword.count));
+        keywords.truncate(top_k);
+
+        Ok(keywords)
+    }
+}
+
+impl Drop for KiwiHandle {
+    fn drop(&mut self) {
+        let res = unsafe {
+            let handle = self.0.read();
             kiwi_close(*handle)
         };
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            panic!("Kiwi close error: {}", err);
+            tracing::error!("failed to close `Kiwi`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `Kiwi`");
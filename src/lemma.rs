@@ -0,0 +1,180 @@
+use std::ops::Range;
+
+use crate::{analyzed::Token, Analyzed, POSTag, Result};
+
+/// 형태소의 기본형
+///
+/// [Lemmatizer::lemmatize] 참고
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lemma {
+    /// 기본형 (예: 먹다, 고맙다, 사랑하다)
+    ///
+    /// 용언이 아닌 형태소는 형태를 그대로 사용합니다.
+    pub form: String,
+    /// 품사 태그
+    ///
+    /// 파생 접미사를 병합한 경우 XSV는 [POSTag::VV], XSA는 [POSTag::VA]가 되며, 불규칙 활용 여부는 유지됩니다.
+    pub tag: POSTag,
+    /// 시작 위치 (UTF-8 바이트 기준)
+    pub byte_begin: usize,
+    /// 끝 위치 (UTF-8 바이트 기준)
+    pub byte_end: usize,
+    /// 기본형을 구성하는 형태소들의 입력 내 위치
+    pub morphs: Range<usize>,
+}
+
+/// 형태소 분석 결과로부터 용언의 기본형을 복원합니다.
+///
+/// VV, VA, VX, VCP, VCN, XSV, XSA 태그(불규칙 활용 태그 포함)의 형태소에 `다`를 붙여 사전에 등재되는 형태로 만듭니다.
+///
+/// 먹/VV -> 먹다, 고맙/VA-I -> 고맙다
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::{KiwiBuilder, Lemmatizer, Match};
+///
+/// let kiwi = KiwiBuilder::new(None, Default::default())
+///     .unwrap()
+///     .build(None, None)
+///     .unwrap();
+///
+/// let analyzed = kiwi.analyze("사랑했습니다", 1, Match::new(), None, None).unwrap();
+///
+/// let lemmas = Lemmatizer::new()
+///     .merge_derivational(true)
+///     .lemmatize_analyzed(&analyzed, 0)
+///     .unwrap()
+///     .unwrap();
+///
+/// assert_eq!(lemmas[0].form, "사랑하다");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Lemmatizer {
+    merge_derivational: bool,
+}
+
+impl Lemmatizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `true`로 설정 시 체언 또는 어근에 바로 붙은 파생 접미사를 하나의 용언으로 병합합니다.
+    ///
+    /// 사랑/NNG + 하/XSV -> 사랑하다/VV, 조용/XR + 하/XSA -> 조용하다/VA
+    pub fn merge_derivational(mut self, r: bool) -> Self {
+        self.merge_derivational = r;
+        self
+    }
+
+    /// 형태소 목록의 기본형을 반환합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens` - 하나의 분석 결과를 이루는 (형태, [Token])의 목록.
+    ///   [Analyzed::to_vec]은 모든 분석 결과를 이어 붙이므로, `top_n`이 2 이상인 경우 [Lemmatizer::lemmatize_analyzed]를 사용합니다.
+    ///
+    /// # Return
+    ///
+    /// 입력 순서대로 정렬된 [Lemma]의 목록. 병합된 형태소는 하나의 [Lemma]가 됩니다.
+    pub fn lemmatize<S>(&self, tokens: &[(S, Token)]) -> Vec<Lemma>
+    where
+        S: AsRef<str>,
+    {
+        let mut lemmas = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let (form, token) = (tokens[i].0.as_ref(), &tokens[i].1);
+
+            if self.merge_derivational {
+                if let Some((suffix, next)) = tokens.get(i + 1).map(|(s, t)| (s.as_ref(), t)) {
+                    if let Some(tag) = derivational_tag(token, next) {
+                        lemmas.push(Lemma {
+                            form: format!("{}{}다", form, suffix),
+                            tag,
+                            byte_begin: token.byte_begin,
+                            byte_end: next.byte_end,
+                            morphs: i..i + 2,
+                        });
+
+                        i += 2;
+                        continue;
+                    }
+                }
+            }
+
+            let form = if is_predicate(token.tag) {
+                format!("{}다", form)
+            } else {
+                form.to_owned()
+            };
+
+            lemmas.push(Lemma {
+                form,
+                tag: token.tag,
+                byte_begin: token.byte_begin,
+                byte_end: token.byte_end,
+                morphs: i..i + 1,
+            });
+
+            i += 1;
+        }
+
+        lemmas
+    }
+
+    /// index번째 분석 결과의 기본형을 반환합니다.
+    ///
+    /// [Lemma::morphs]는 분석 결과 내 형태소의 번호(word_num)입니다.
+    pub fn lemmatize_analyzed(
+        &self,
+        analyzed: &Analyzed,
+        index: usize,
+    ) -> Result<Option<Vec<Lemma>>> {
+        let Some(word_num) = analyzed.word_num(index) else {
+            return Ok(None);
+        };
+
+        let mut tokens = Vec::with_capacity(word_num);
+
+        for j in 0..word_num {
+            // word_num 범위 내에서는 항상 값이 존재함
+            if let (Some(form), Some(token)) = (analyzed.form(index, j)?, analyzed.token(index, j)?)
+            {
+                tokens.push((form, token));
+            }
+        }
+
+        Ok(Some(self.lemmatize(&tokens)))
+    }
+}
+
+/// 기본형을 복원할 형태소인지 확인합니다. 용언과 함께 용언 파생 접미사도 포함합니다.
+#[inline]
+fn is_predicate(tag: POSTag) -> bool {
+    tag.is_predicate() || matches!(tag.base(), POSTag::XSV | POSTag::XSA)
+}
+
+/// 체언 또는 어근 뒤에 공백 없이 파생 접미사가 붙은 경우 병합된 용언의 품사 태그를 반환합니다.
+#[inline]
+fn derivational_tag(stem: &Token, suffix: &Token) -> Option<POSTag> {
+    if !matches!(stem.tag, POSTag::NNG | POSTag::NNP | POSTag::XR) {
+        return None;
+    }
+
+    if stem.byte_end != suffix.byte_begin {
+        return None;
+    }
+
+    let irregular = suffix.tag.get_num() & POSTag::IRREGULAR;
+
+    let tag = match suffix.tag.base() {
+        POSTag::XSV => POSTag::VV,
+        POSTag::XSA => POSTag::VA,
+        _ => return None,
+    };
+
+    Some(POSTag(tag.get_num() | irregular))
+}
@@ -5,30 +5,67 @@
 )]
 
 pub mod analyzed;
+#[cfg(feature = "tokio")]
+mod async_kiwi;
 mod bindings;
 mod builder;
+#[cfg(feature = "config")]
+mod config;
 pub mod error;
 pub mod extracted;
+mod joiner;
+mod keyword;
 mod kiwi;
+mod lemma;
 mod r#match;
+mod morpheme;
 mod morpheme_set;
 mod pos_tag;
+#[cfg(feature = "tokenizers")]
+mod pre_tokenizer;
 mod pretokenized;
+pub mod sentences;
+mod sw_tokenizer;
+pub mod tag_set;
+#[cfg(feature = "tantivy")]
+mod tantivy;
 mod trampoline;
 mod typo;
+mod ud;
 
-pub use analyzed::Analyzed;
+pub use analyzed::{AnalysisResult, Analyzed};
+#[cfg(feature = "tokio")]
+pub use async_kiwi::*;
 pub use builder::*;
+#[cfg(feature = "config")]
+pub use config::*;
 pub use error::*;
 pub use extracted::Extracted;
+pub use joiner::*;
+pub use keyword::{Keyword, Stopwords};
 pub use kiwi::*;
+pub use lemma::*;
+pub use morpheme::*;
 pub use morpheme_set::*;
 pub use pos_tag::*;
+#[cfg(feature = "tokenizers")]
+pub use pre_tokenizer::*;
 pub use pretokenized::*;
 pub use r#match::*;
+pub use sentences::Sentences;
+pub use sw_tokenizer::*;
+pub use tag_set::TagSet;
+#[cfg(feature = "tantivy")]
+pub use tantivy::*;
 pub use typo::{DefaultTypoSet, DefaultTypoTransformer, TypoTransformer};
+pub use ud::UPos;
 
-use std::ffi::CStr;
+use std::{
+    ffi::{CStr, CString},
+    path::Path,
+};
+
+use widestring::{U16CString, U16Str};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -40,19 +77,19 @@ type KiwiRc<T> = std::rc::Rc<T>;
 pub fn kiwi_version() -> String {
     let cstr = unsafe { CStr::from_ptr(bindings::kiwi_version()) };
 
-    cstr.to_owned().into_string().unwrap()
+    cstr.to_string_lossy().into_owned()
 }
 
 pub fn get_script_name(script: u8) -> Option<String> {
     unsafe {
         let script_name = CStr::from_ptr(bindings::kiwi_get_script_name(script));
-        let script_name = script_name.to_owned().into_string().unwrap();
+        let script_name = script_name.to_string_lossy();
 
         if script_name == "Unknown" {
             return None;
         }
 
-        Some(script_name)
+        Some(script_name.into_owned())
     }
 }
 
@@ -64,7 +101,7 @@ pub(crate) fn kiwi_error() -> Option<String> {
             return None;
         }
 
-        let err = CStr::from_ptr(err).to_owned().into_string().unwrap();
+        let err = CStr::from_ptr(err).to_string_lossy().into_owned();
 
         bindings::kiwi_clear_error();
 
@@ -76,6 +113,76 @@ pub(crate) fn kiwi_error() -> Option<String> {
     }
 }
 
+/// 분석할 텍스트를 C 문자열로 변환합니다.
+///
+/// null 문자는 공백으로 치환합니다. 두 문자 모두 UTF-8에서 1바이트, UTF-16에서 1글자를 차지하므로
+/// 분석 결과의 위치 정보는 원본 텍스트를 기준으로 그대로 유지됩니다.
+pub(crate) fn text_to_cstring(text: &str) -> CString {
+    let bytes = text
+        .bytes()
+        .map(|b| if b == 0 { b' ' } else { b })
+        .collect::<Vec<_>>();
+
+    // SAFETY: 모든 null 문자를 공백으로 치환함
+    unsafe { CString::from_vec_unchecked(bytes) }
+}
+
+/// 분석할 텍스트를 UTF-16 C 문자열로 변환합니다.
+///
+/// [text_to_cstring] 참고
+pub(crate) fn text_to_u16cstring(text: &U16Str) -> U16CString {
+    let units = text
+        .as_slice()
+        .iter()
+        .map(|&c| if c == 0 { b' ' as u16 } else { c })
+        .collect::<Vec<_>>();
+
+    // SAFETY: 모든 null 문자를 공백으로 치환함
+    unsafe { U16CString::from_vec_unchecked(units) }
+}
+
+/// 형태, 패턴 등 그대로 전달되어야 하는 문자열을 C 문자열로 변환합니다.
+///
+/// # Errors
+/// null 문자가 포함된 경우 [Error::InvalidInput]
+pub(crate) fn to_cstring(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|err| Error::InvalidInput(err.to_string()))
+}
+
+/// [to_cstring]의 UTF-16 버전
+pub(crate) fn to_u16cstring(s: &U16Str) -> Result<U16CString> {
+    U16CString::from_ustr(s).map_err(|err| Error::InvalidInput(err.to_string()))
+}
+
+/// 경로를 C 문자열로 변환합니다.
+///
+/// # Errors
+/// 경로가 UTF-8이 아니거나 null 문자가 포함된 경우 [Error::InvalidInput]
+pub(crate) fn path_to_cstring(path: &Path) -> Result<CString> {
+    let path_str = path.to_str().ok_or_else(|| {
+        Error::InvalidInput(format!("path is not valid UTF-8: {}", path.display()))
+    })?;
+
+    to_cstring(path_str)
+}
+
+/// `0.0`이 정상적인 값일 수도 있는 네이티브 함수를 호출합니다.
+///
+/// 반환 값이 `0.0`인 경우에만 에러가 기록되었는지 확인하여 실패 여부를 판단합니다.
+pub(crate) fn check_float(f: impl FnOnce() -> f32) -> Result<f32> {
+    unsafe { bindings::kiwi_clear_error() };
+
+    let res = f();
+
+    if res == 0.0 {
+        if let Some(err) = kiwi_error() {
+            return Err(Error::Native(err));
+        }
+    }
+
+    Ok(res)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{get_script_name, kiwi_version};
@@ -115,4 +222,168 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_kiwi_builder_callback_panic() -> anyhow::Result<()> {
+        use crate::{Error, KiwiBuilder, POSTag};
+
+        let kiwi_builder = KiwiBuilder::new(None, Default::default())?;
+
+        let err = kiwi_builder
+            .add_rule(POSTag::VV, |_| panic!("replacer panicked"), 0.0)
+            .err()
+            .unwrap();
+
+        assert!(
+            matches!(err, Error::CallbackPanic(ref message) if message == "replacer panicked"),
+            "{:?}",
+            err
+        );
+
+        let kiwi_builder = KiwiBuilder::new(None, Default::default())?;
+
+        let err = kiwi_builder
+            .extract_words(|_| panic!("reader panicked"), 10, 10, 0.0, -3.0)
+            .err()
+            .unwrap();
+
+        assert!(matches!(err, Error::CallbackPanic(_)), "{:?}", err);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kiwi_builder_callback_nul() -> anyhow::Result<()> {
+        use crate::{Error, KiwiBuilder, POSTag};
+
+        let kiwi_builder = KiwiBuilder::new(None, Default::default())?;
+
+        let err = kiwi_builder
+            .add_rule(POSTag::VV, |form| format!("{}\0", form), 0.0)
+            .err()
+            .unwrap();
+
+        assert!(matches!(err, Error::InvalidInput(_)), "{:?}", err);
+
+        let kiwi_builder = KiwiBuilder::new(None, Default::default())?;
+
+        let err = kiwi_builder
+            .extract_words(
+                |i| {
+                    if i == 0 {
+                        "키위\0".to_owned()
+                    } else {
+                        String::new()
+                    }
+                },
+                10,
+                10,
+                0.0,
+                -3.0,
+            )
+            .err()
+            .unwrap();
+
+        assert!(matches!(err, Error::InvalidInput(_)), "{:?}", err);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_kiwi_options_model_type_serde() -> anyhow::Result<()> {
+        use crate::{
+            bindings::{KIWI_BUILD_MODEL_TYPE_KNLM, KIWI_BUILD_MODEL_TYPE_SBG},
+            KiwiOptions,
+        };
+
+        let options = serde_json::from_str::<KiwiOptions>(r#"{ "model_type_sbg": true }"#)?;
+
+        assert_eq!(
+            options.finish() & KIWI_BUILD_MODEL_TYPE_SBG,
+            KIWI_BUILD_MODEL_TYPE_SBG
+        );
+        assert_eq!(options.finish() & KIWI_BUILD_MODEL_TYPE_KNLM, 0);
+
+        let options = serde_json::from_value::<KiwiOptions>(serde_json::to_value(&options)?)?;
+
+        assert_eq!(
+            options.finish() & KIWI_BUILD_MODEL_TYPE_SBG,
+            KIWI_BUILD_MODEL_TYPE_SBG
+        );
+        assert_eq!(options.finish() & KIWI_BUILD_MODEL_TYPE_KNLM, 0);
+
+        let options = serde_json::from_str::<KiwiOptions>(r#"{ "model_type_knlm": false }"#)?;
+
+        assert_eq!(options.finish() & KIWI_BUILD_MODEL_TYPE_KNLM, 0);
+        assert_eq!(
+            options.finish() & KIWI_BUILD_MODEL_TYPE_SBG,
+            KIWI_BUILD_MODEL_TYPE_SBG
+        );
+
+        let options = serde_json::from_str::<KiwiOptions>("{}")?;
+
+        assert_eq!(options.finish(), KiwiOptions::default().finish());
+
+        let res = serde_json::from_str::<KiwiOptions>(
+            r#"{ "model_type_knlm": true, "model_type_sbg": true }"#,
+        );
+
+        assert!(res.is_err());
+
+        // 옵션 이름을 잘못 적은 경우 무시하지 않음
+        let res = serde_json::from_str::<KiwiOptions>(r#"{ "model_typ_sbg": true }"#);
+
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dict_parse_error_line() {
+        use crate::Error;
+
+        let err = Error::dict_parse("user.dict", "wrong format at line 3: 벨리타".to_owned());
+
+        assert!(
+            matches!(err, Error::DictParse { line: Some(3), .. }),
+            "{:?}",
+            err
+        );
+        assert_eq!(err.message(), "wrong format at line 3: 벨리타");
+
+        let err = Error::dict_parse("user.dict", "cannot open file".to_owned());
+
+        assert!(
+            matches!(err, Error::DictParse { line: None, .. }),
+            "{:?}",
+            err
+        );
+
+        let err = Error::dict_parse(
+            "user.dict",
+            "[loadUserDictionary] Wrong dictionary format at line 12 : 온라인\tNNG".to_owned(),
+        );
+
+        assert!(
+            matches!(err, Error::DictParse { line: Some(12), .. }),
+            "{:?}",
+            err
+        );
+
+        // 다른 단어에 포함된 "line"은 줄 번호로 취급하지 않음
+        for message in [
+            "cannot load online 3 dictionary",
+            "pipeline 7 failed",
+            "line is too long",
+        ] {
+            let err = Error::dict_parse("user.dict", message.to_owned());
+
+            assert!(
+                matches!(err, Error::DictParse { line: None, .. }),
+                "{:?}",
+                err
+            );
+        }
+    }
 }
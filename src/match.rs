@@ -6,7 +6,7 @@ use crate::bindings::*;
 ///
 /// # Default
 /// 모든 옵션이 비활성됨
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Match {
     url: bool,
     email: bool,
@@ -230,6 +230,109 @@ impl Match {
         }
     }
 
+    /// 옵션 이름 목록
+    ///
+    /// 각 옵션의 이름은 해당 옵션을 설정하는 메서드의 이름과 같습니다.
+    pub const FLAG_NAMES: [&'static str; 17] = [
+        "url",
+        "email",
+        "hashtag",
+        "mention",
+        "serial",
+        "emoji",
+        "normalize_coda",
+        "join_noun_prefix",
+        "join_noun_suffix",
+        "join_verb_suffix",
+        "join_adj_suffix",
+        "join_adv_suffix",
+        "split_complex",
+        "z_coda",
+        "compatible_jamo",
+        "split_saisiot",
+        "merge_saisiot",
+    ];
+
+    /// (옵션 이름, 활성화 여부) 목록을 반환합니다.
+    ///
+    /// 순서는 [Match::FLAG_NAMES]와 같습니다.
+    pub fn flags(&self) -> [(&'static str, bool); 17] {
+        let Match {
+            url,
+            email,
+            hashtag,
+            mention,
+            serial,
+            emoji,
+            normalize_coda,
+            join_noun_prefix,
+            join_noun_suffix,
+            join_verb_suffix,
+            join_adj_suffix,
+            join_adv_suffix,
+            split_complex,
+            z_coda,
+            compatible_jamo,
+            split_saisiot,
+            merge_saisiot,
+        } = *self;
+
+        let values = [
+            url,
+            email,
+            hashtag,
+            mention,
+            serial,
+            emoji,
+            normalize_coda,
+            join_noun_prefix,
+            join_noun_suffix,
+            join_verb_suffix,
+            join_adj_suffix,
+            join_adv_suffix,
+            split_complex,
+            z_coda,
+            compatible_jamo,
+            split_saisiot,
+            merge_saisiot,
+        ];
+
+        std::array::from_fn(|i| (Self::FLAG_NAMES[i], values[i]))
+    }
+
+    /// 이름으로 옵션을 설정합니다.
+    ///
+    /// 이름은 [Match::FLAG_NAMES] 참고
+    ///
+    /// # Return
+    /// 존재하지 않는 옵션 이름인 경우 `false`를 반환합니다.
+    pub fn set_flag(&mut self, name: &str, r: bool) -> bool {
+        let flag = match name {
+            "url" => &mut self.url,
+            "email" => &mut self.email,
+            "hashtag" => &mut self.hashtag,
+            "mention" => &mut self.mention,
+            "serial" => &mut self.serial,
+            "emoji" => &mut self.emoji,
+            "normalize_coda" => &mut self.normalize_coda,
+            "join_noun_prefix" => &mut self.join_noun_prefix,
+            "join_noun_suffix" => &mut self.join_noun_suffix,
+            "join_verb_suffix" => &mut self.join_verb_suffix,
+            "join_adj_suffix" => &mut self.join_adj_suffix,
+            "join_adv_suffix" => &mut self.join_adv_suffix,
+            "split_complex" => &mut self.split_complex,
+            "z_coda" => &mut self.z_coda,
+            "compatible_jamo" => &mut self.compatible_jamo,
+            "split_saisiot" => &mut self.split_saisiot,
+            "merge_saisiot" => &mut self.merge_saisiot,
+            _ => return false,
+        };
+
+        *flag = r;
+
+        true
+    }
+
     pub(crate) fn finish(&self) -> i32 {
         let Match {
             url,
@@ -308,3 +411,49 @@ impl Match {
         flag as i32
     }
 }
+
+/// 활성화된 옵션의 이름 목록으로 직렬화합니다.
+///
+/// 옵션의 이름은 각 메서드의 이름과 같습니다. 예) `["url", "email", "split_complex"]`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Match {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        let flags = self.flags();
+        let mut seq = serializer.serialize_seq(None)?;
+
+        for (name, enabled) in flags {
+            if enabled {
+                seq.serialize_element(name)?;
+            }
+        }
+
+        seq.end()
+    }
+}
+
+/// 활성화할 옵션의 이름 목록으로부터 역직렬화합니다.
+///
+/// 목록에 포함되지 않은 옵션은 모두 비활성화됩니다.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Match {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        let mut r = Match::new();
+
+        for name in names {
+            if !r.set_flag(&name, true) {
+                return Err(serde::de::Error::unknown_variant(&name, &Match::FLAG_NAMES));
+            }
+        }
+
+        Ok(r)
+    }
+}
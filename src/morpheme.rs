@@ -0,0 +1,29 @@
+use crate::POSTag;
+
+/// [Kiwi](crate::Kiwi)의 사전에 등록된 형태소의 정보
+///
+/// [Kiwi::find_morphemes](crate::Kiwi::find_morphemes), [Kiwi::morpheme](crate::Kiwi::morpheme) 참고
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Morpheme {
+    /// 형태소 id
+    pub id: u32,
+    /// 형태 (UTF-8)
+    pub form: String,
+    /// 품사 태그
+    pub tag: POSTag,
+    /// 의미 번호
+    pub sense_id: u8,
+    /// 사용자 점수
+    ///
+    /// [KiwiBuilder::add_word](crate::KiwiBuilder::add_word) 등으로 등록할 때 지정한 점수. 기본 사전의 형태소는 `0`
+    pub user_score: f32,
+    /// 언어 모델 내에서 사용되는 형태소 id
+    ///
+    /// 사전 미등재 단어(UNK)로 처리되는 형태소의 경우 `0`
+    pub lm_morpheme_id: u32,
+    /// 원본 형태소 id
+    ///
+    /// [KiwiBuilder::add_alias_word](crate::KiwiBuilder::add_alias_word)로 등록한 형태소의 경우 원본 형태소의 id, 그 외에는 자기 자신의 id
+    pub orig_morpheme_id: u32,
+}
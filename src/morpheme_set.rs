@@ -1,13 +1,17 @@
-use std::{ffi::CString, str::FromStr};
+use std::collections::HashSet;
 
 use parking_lot::Mutex;
-use widestring::{U16CString, U16Str};
+use widestring::U16Str;
 
-use crate::{bindings::*, kiwi_error, Error, Kiwi, KiwiRc, POSTag, Result};
+use crate::{
+    bindings::*, kiwi_error, to_cstring, to_u16cstring, Error, Kiwi, KiwiRc, POSTag, Result,
+};
 
 #[derive(Clone)]
 pub struct MorphemeSet {
     pub(crate) handle: KiwiRc<Mutex<kiwi_morphset_h>>,
+    /// 추가된 (형태, 품사 태그) 목록. 네이티브 핸들로는 포함 여부를 조회할 수 없으므로 따로 보관함
+    entries: KiwiRc<Mutex<HashSet<(String, POSTag)>>>,
 }
 
 #[cfg(feature = "impl_send")]
@@ -23,16 +27,18 @@ impl MorphemeSet {
         Self {
             #[allow(clippy::arc_with_non_send_sync)]
             handle: KiwiRc::new(Mutex::new(handle)),
+            #[allow(clippy::arc_with_non_send_sync)]
+            entries: KiwiRc::new(Mutex::new(HashSet::new())),
         }
     }
 
     pub fn add(&self, form: &str, tag: POSTag) -> Result<bool> {
-        let form = CString::from_str(form).unwrap();
-        let tag = CString::from_str(tag.as_str()).unwrap();
+        let c_form = to_cstring(form)?;
+        let c_tag = to_cstring(tag.as_str())?;
 
         let res = unsafe {
             let handle = self.handle.lock();
-            kiwi_morphset_add(*handle, form.as_ptr(), tag.as_ptr())
+            kiwi_morphset_add(*handle, c_form.as_ptr(), c_tag.as_ptr())
         };
 
         if res < 0 {
@@ -40,16 +46,22 @@ impl MorphemeSet {
             return Err(Error::Native(err));
         }
 
+        // 사전에 없는 형태소는 추가되지 않고 0을 반환함
+        if res > 0 {
+            self.entries.lock().insert((form.to_owned(), tag));
+        }
+
         Ok(res > 0)
     }
 
     pub fn add_w(&self, form: impl AsRef<U16Str>, tag: POSTag) -> Result<bool> {
-        let form = U16CString::from_ustr(form).unwrap();
-        let tag = CString::from_str(tag.as_str()).unwrap();
+        let form = form.as_ref();
+        let c_form = to_u16cstring(form)?;
+        let c_tag = to_cstring(tag.as_str())?;
 
         let res = unsafe {
             let handle = self.handle.lock();
-            kiwi_morphset_add_w(*handle, form.as_ptr(), tag.as_ptr())
+            kiwi_morphset_add_w(*handle, c_form.as_ptr(), c_tag.as_ptr())
         };
 
         if res < 0 {
@@ -57,8 +69,19 @@ impl MorphemeSet {
             return Err(Error::Native(err));
         }
 
+        if res > 0 {
+            self.entries.lock().insert((form.to_string_lossy(), tag));
+        }
+
         Ok(res > 0)
     }
+
+    /// [MorphemeSet::add] 또는 [MorphemeSet::add_w]로 추가한 형태소인지 확인합니다.
+    ///
+    /// 사전에 없어 추가되지 않은 형태소는 포함되지 않습니다. 사전에 없는 형태소도 불용어로 다루려면 [Stopwords](crate::Stopwords)를 사용합니다.
+    pub fn contains(&self, form: &str, tag: POSTag) -> bool {
+        self.entries.lock().contains(&(form.to_owned(), tag))
+    }
 }
 
 impl Drop for MorphemeSet {
@@ -73,8 +96,9 @@ impl Drop for MorphemeSet {
         };
 
         if res != 0 {
-            let err = kiwi_error();
-            panic!("MorhphemeSet close error: {:?}", err);
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `MorphemeSet`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `MorphemeSet`");
@@ -19,6 +19,8 @@ macro_rules! impl_pos_tag {
             pub const MAX: u8 = 61;
             /// 불규칙 활용을 하는 동/형용사를 나타내는데 사용함
             pub const IRREGULAR: u8 = 0x80;
+            /// 정의된 모든 품사 태그 (불규칙 활용 태그 포함)
+            pub const ALL: &'static [POSTag] = &[$(POSTag::$name,)*];
 
             #[inline]
             pub const fn get_num(&self) -> u8 {
@@ -36,7 +38,7 @@ macro_rules! impl_pos_tag {
         }
 
         impl ::std::str::FromStr for POSTag {
-            type Err = u8;
+            type Err = crate::Error;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 let r = match s {
@@ -48,7 +50,7 @@ macro_rules! impl_pos_tag {
                     )*
                     )?
                     )*
-                    _ => return Err(POSTag::MAX),
+                    _ => return Err(crate::Error::InvalidTag(s.to_owned())),
                 };
 
                 Ok(r)
@@ -57,6 +59,93 @@ macro_rules! impl_pos_tag {
     };
 }
 
+impl POSTag {
+    /// 불규칙 활용 여부를 제거한 품사 태그를 반환합니다.
+    ///
+    /// VV-I -> VV, XSA-I -> XSA
+    #[inline]
+    pub const fn base(&self) -> POSTag {
+        POSTag(self.0 & !POSTag::IRREGULAR)
+    }
+
+    /// 불규칙 활용을 하는 동/형용사 태그인지 확인합니다.
+    #[inline]
+    pub const fn is_irregular(&self) -> bool {
+        self.0 & POSTag::IRREGULAR != 0
+    }
+
+    /// 체언(NNG, NNP, NNB, NR, NP)인지 확인합니다.
+    #[inline]
+    pub const fn is_nominal(&self) -> bool {
+        matches!(
+            *self,
+            POSTag::NNG | POSTag::NNP | POSTag::NNB | POSTag::NR | POSTag::NP
+        )
+    }
+
+    /// 용언(VV, VA, VX, VCP, VCN)인지 확인합니다. 불규칙 활용 태그를 포함합니다.
+    #[inline]
+    pub const fn is_predicate(&self) -> bool {
+        matches!(
+            self.base(),
+            POSTag::VV | POSTag::VA | POSTag::VX | POSTag::VCP | POSTag::VCN
+        )
+    }
+
+    /// 수식언(MM, MAG, MAJ)인지 확인합니다.
+    #[inline]
+    pub const fn is_modifier(&self) -> bool {
+        matches!(*self, POSTag::MM | POSTag::MAG | POSTag::MAJ)
+    }
+
+    /// 접두사 또는 접미사(XPN, XSN, XSV, XSA, XSM)인지 확인합니다. 불규칙 활용 태그를 포함합니다.
+    #[inline]
+    pub const fn is_affix(&self) -> bool {
+        matches!(
+            self.base(),
+            POSTag::XPN | POSTag::XSN | POSTag::XSV | POSTag::XSA | POSTag::XSM
+        )
+    }
+
+    /// 조사(JKS ~ JC)인지 확인합니다.
+    #[inline]
+    pub const fn is_josa(&self) -> bool {
+        self.0 >= POSTag::JKS.0 && self.0 <= POSTag::JC.0
+    }
+
+    /// 어미(EP, EF, EC, ETN, ETM)인지 확인합니다.
+    #[inline]
+    pub const fn is_eomi(&self) -> bool {
+        self.0 >= POSTag::EP.0 && self.0 <= POSTag::ETM.0
+    }
+
+    /// 부호(SF ~ SB)인지 확인합니다.
+    #[inline]
+    pub const fn is_symbol(&self) -> bool {
+        self.0 >= POSTag::SF.0 && self.0 <= POSTag::SB.0
+    }
+
+    /// 외국어(SL), 한자(SH), 숫자(SN)인지 확인합니다.
+    #[inline]
+    pub const fn is_foreign(&self) -> bool {
+        matches!(*self, POSTag::SL | POSTag::SH | POSTag::SN)
+    }
+
+    /// 웹 개체(W_URL ~ W_EMOJI)인지 확인합니다.
+    ///
+    /// [Match](crate::Match)에서 해당 옵션을 켠 경우에만 분석 결과에 나타납니다.
+    #[inline]
+    pub const fn is_web(&self) -> bool {
+        self.0 >= POSTag::W_URL.0 && self.0 <= POSTag::W_EMOJI.0
+    }
+
+    /// 사용자 정의 태그(USER0 ~ USER4)인지 확인합니다.
+    #[inline]
+    pub const fn is_user(&self) -> bool {
+        self.0 >= POSTag::USER0.0 && self.0 <= POSTag::USER4.0
+    }
+}
+
 impl Display for POSTag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.as_str().fmt(f)
@@ -69,6 +158,30 @@ impl AsRef<str> for POSTag {
     }
 }
 
+/// [POSTag::as_str]의 문자열로 직렬화합니다.
+#[cfg(feature = "serde")]
+impl serde::Serialize for POSTag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// [POSTag]의 [FromStr](std::str::FromStr) 구현을 이용해 문자열로부터 역직렬화합니다.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for POSTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl Deref for POSTag {
     type Target = u8;
 
@@ -0,0 +1,130 @@
+use tokenizers::{normalizer::Range, NormalizedString, PreTokenizedString, PreTokenizer};
+
+use crate::{Kiwi, Match};
+
+/// Hugging Face tokenizers에서 사용할 수 있는 형태소 단위 pre-tokenizer
+///
+/// 정규화된 문자열을 형태소 단위로 나눕니다. 형태소의 위치는 [Token::chr_position](crate::analyzed::Token::chr_position)(UTF-16 기준)을
+/// UTF-8 바이트 위치로 변환한 [Token::byte_begin](crate::analyzed::Token::byte_begin), [Token::byte_end](crate::analyzed::Token::byte_end)를 사용하므로,
+/// 인코딩 결과의 offset은 원본 문자열을 기준으로 올바르게 계산됩니다.
+///
+/// 공백은 버려지며, `했다`의 `하/XSV + 었/EP`처럼 원문에서 겹치는 형태소들은 하나로 묶입니다.
+///
+/// 나뉜 문자열의 내용은 바꾸지 않으므로 모델의 입력은 원문의 일부 그대로입니다.
+/// 각 조각의 품사 태그가 필요한 경우 [KiwiPreTokenizer::tagged_spans]로 같은 단위의 위치와 태그를 따로 얻을 수 있습니다.
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::{KiwiBuilder, KiwiPreTokenizer};
+/// use tokenizers::{OffsetReferential, OffsetType, PreTokenizedString, PreTokenizer};
+///
+/// let kiwi = KiwiBuilder::new(None, Default::default())
+///     .unwrap()
+///     .build(None, None)
+///     .unwrap();
+///
+/// let pre_tokenizer = KiwiPreTokenizer::new(kiwi);
+///
+/// let mut pretokenized = PreTokenizedString::from("밥을 먹자");
+/// pre_tokenizer.pre_tokenize(&mut pretokenized).unwrap();
+///
+/// let splits = pretokenized.get_splits(OffsetReferential::Original, OffsetType::Byte);
+///
+/// assert_eq!(splits[0].0, "밥");
+/// assert_eq!(splits[0].1, (0, 3));
+///
+/// let spans = pre_tokenizer.tagged_spans("밥을 먹자").unwrap();
+///
+/// assert_eq!(spans[0], (0, 3, "NNG".to_owned()));
+/// ```
+#[derive(Clone)]
+pub struct KiwiPreTokenizer {
+    kiwi: Kiwi,
+    match_options: Match,
+    append_tags_to_input: bool,
+}
+
+impl KiwiPreTokenizer {
+    pub fn new(kiwi: Kiwi) -> Self {
+        Self {
+            kiwi,
+            match_options: Match::new(),
+            append_tags_to_input: false,
+        }
+    }
+
+    /// [Match] 참고
+    pub fn match_options(mut self, r: Match) -> Self {
+        self.match_options = r;
+        self
+    }
+
+    /// `true`로 설정 시 나뉜 문자열 뒤에 `/NNG`처럼 품사 태그를 덧붙입니다. 기본값은 `false`입니다.
+    ///
+    /// **모델의 입력이 바뀝니다.** 덧붙인 태그도 모델이 토큰화하므로, 태그가 붙은 문자열로 학습한 vocab에서만 사용해야 합니다.
+    /// 모델의 입력을 바꾸지 않고 태그를 얻으려면 [KiwiPreTokenizer::tagged_spans]를 사용합니다.
+    ///
+    /// 여러 형태소가 하나로 묶인 경우 `했/XSV+EP`처럼 `+`로 이어붙입니다.
+    /// 덧붙인 태그는 offset 계산에 영향을 주지 않습니다.
+    pub fn append_tags_to_input(mut self, r: bool) -> Self {
+        self.append_tags_to_input = r;
+        self
+    }
+
+    /// 문자열을 [PreTokenizer::pre_tokenize]와 같은 단위로 나눈 위치와 품사 태그를 반환합니다.
+    ///
+    /// # Return
+    ///
+    /// (시작 위치, 끝 위치, 품사 태그)의 목록. 위치는 UTF-8 바이트 기준이며,
+    /// 여러 형태소가 하나로 묶인 경우 품사 태그는 `XSV+EP`처럼 `+`로 이어붙입니다.
+    pub fn tagged_spans(&self, text: &str) -> crate::Result<Vec<(usize, usize, String)>> {
+        let analyzed = self.kiwi.analyze(text, 1, self.match_options, None, None)?;
+
+        let mut spans: Vec<(usize, usize, String)> = Vec::new();
+
+        for (_, token) in analyzed.to_vec()? {
+            if token.byte_begin == token.byte_end {
+                continue;
+            }
+
+            match spans.last_mut() {
+                Some((_, end, tags)) if token.byte_begin < *end => {
+                    *end = (*end).max(token.byte_end);
+                    tags.push('+');
+                    tags.push_str(token.tag.as_str());
+                }
+                _ => spans.push((
+                    token.byte_begin,
+                    token.byte_end,
+                    token.tag.as_str().to_owned(),
+                )),
+            }
+        }
+
+        Ok(spans)
+    }
+
+    fn split(&self, normalized: NormalizedString) -> tokenizers::Result<Vec<NormalizedString>> {
+        self.tagged_spans(normalized.get())?
+            .into_iter()
+            .map(|(begin, end, tags)| {
+                let mut split = normalized
+                    .slice(Range::Normalized(begin..end))
+                    .ok_or("morpheme offsets are not on char boundaries")?;
+
+                if self.append_tags_to_input {
+                    split.append(&format!("/{}", tags));
+                }
+
+                Ok(split)
+            })
+            .collect()
+    }
+}
+
+impl PreTokenizer for KiwiPreTokenizer {
+    fn pre_tokenize(&self, pretokenized: &mut PreTokenizedString) -> tokenizers::Result<()> {
+        pretokenized.split(|_, normalized| self.split(normalized))
+    }
+}
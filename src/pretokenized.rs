@@ -1,9 +1,7 @@
-use std::{ffi::CString, str::FromStr};
-
 use parking_lot::Mutex;
-use widestring::{U16CString, U16Str};
+use widestring::U16Str;
 
-use crate::{bindings::*, kiwi_error, Error, KiwiRc, POSTag, Result};
+use crate::{bindings::*, kiwi_error, to_cstring, to_u16cstring, Error, KiwiRc, POSTag, Result};
 
 #[derive(Clone)]
 pub struct Pretokenized {
@@ -77,8 +75,8 @@ impl Pretokenized {
         begin: usize,
         end: usize,
     ) -> Result<()> {
-        let form = CString::from_str(form).unwrap();
-        let tag = CString::from_str(tag.as_str()).unwrap();
+        let form = to_cstring(form)?;
+        let tag = to_cstring(tag.as_str())?;
 
         let res = unsafe {
             let handle = self.handle.lock();
@@ -121,8 +119,8 @@ impl Pretokenized {
         begin: usize,
         end: usize,
     ) -> Result<()> {
-        let form = U16CString::from_ustr(form).unwrap();
-        let tag = CString::from_str(tag.as_str()).unwrap();
+        let form = to_u16cstring(form.as_ref())?;
+        let tag = to_cstring(tag.as_str())?;
 
         let res = unsafe {
             let handle = self.handle.lock();
@@ -157,8 +155,9 @@ impl Drop for Pretokenized {
         };
 
         if res != 0 {
-            let err = kiwi_error();
-            panic!("Pretokenized close error: {:?}", err);
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `Pretokenized`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `Pretokenized`");
@@ -0,0 +1,201 @@
+use crate::{analyzed::Token, bindings::*, kiwi_error, Analyzed, Error, KiwiRc, Result};
+
+/// [Kiwi::split_into_sents] 또는 [Kiwi::split_into_sents_w]의 반환 값
+///
+/// [Kiwi::split_into_sents]: crate::Kiwi::split_into_sents
+/// [Kiwi::split_into_sents_w]: crate::Kiwi::split_into_sents_w
+#[derive(Clone)]
+pub struct Sentences {
+    pub(crate) handle: KiwiRc<kiwi_ss_h>,
+    size: usize,
+    analyzed: Option<Analyzed>,
+}
+
+#[cfg(feature = "impl_send")]
+unsafe impl Send for Sentences {}
+// unsafe impl Sync for Sentences {}
+
+#[inline]
+fn size(handle: kiwi_ss_h) -> Result<usize> {
+    unsafe {
+        let size = kiwi_ss_size(handle);
+
+        if size < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        Ok(size as usize)
+    }
+}
+
+impl Sentences {
+    pub(crate) fn new(handle: kiwi_ss_h, analyzed: Option<Analyzed>) -> Result<Self> {
+        let size = match size(handle) {
+            Ok(size) => size,
+            Err(err) => {
+                // 핸들을 [Sentences]로 감싸기 전에 실패한 경우 직접 해제함
+                unsafe { kiwi_ss_close(handle) };
+                return Err(err);
+            }
+        };
+
+        Ok(Self {
+            #[allow(clippy::arc_with_non_send_sync)]
+            handle: KiwiRc::new(handle),
+            size,
+            analyzed,
+        })
+    }
+
+    #[inline]
+    fn check_index(&self, index: usize) -> Option<()> {
+        (self.size > index).then_some(())
+    }
+
+    /// 분할된 문장의 개수를 반환합니다.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// index번째 문장의 시작 위치를 반환합니다.
+    ///
+    /// [Kiwi::split_into_sents](crate::Kiwi::split_into_sents)로 분할한 경우 UTF-8 문자열의 바이트 단위,
+    /// [Kiwi::split_into_sents_w](crate::Kiwi::split_into_sents_w)로 분할한 경우 UTF-16 문자열의 글자 단위입니다.
+    pub fn begin(&self, index: usize) -> Result<Option<usize>> {
+        self.check_index(index)
+            .map(|_| self.begin_unchecked(index))
+            .transpose()
+    }
+
+    #[inline]
+    fn begin_unchecked(&self, index: usize) -> Result<usize> {
+        unsafe {
+            let begin = kiwi_ss_begin_position(*self.handle, index as i32);
+
+            if begin < 0 {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
+            }
+
+            Ok(begin as usize)
+        }
+    }
+
+    /// index번째 문장의 끝 위치를 반환합니다.
+    ///
+    /// 단위는 [Sentences::begin]과 같습니다.
+    pub fn end(&self, index: usize) -> Result<Option<usize>> {
+        self.check_index(index)
+            .map(|_| self.end_unchecked(index))
+            .transpose()
+    }
+
+    #[inline]
+    fn end_unchecked(&self, index: usize) -> Result<usize> {
+        unsafe {
+            let end = kiwi_ss_end_position(*self.handle, index as i32);
+
+            if end < 0 {
+                let err = kiwi_error().unwrap_or_default();
+                return Err(Error::Native(err));
+            }
+
+            Ok(end as usize)
+        }
+    }
+
+    /// 문장 분할 과정에서 생성된 형태소 분석 결과를 반환합니다.
+    ///
+    /// `with_analyzed`를 `true`로 설정하여 분할한 경우에만 값이 존재합니다.
+    #[inline]
+    pub fn analyzed(&self) -> Option<&Analyzed> {
+        self.analyzed.as_ref()
+    }
+
+    /// index번째 문장에 속하는 형태소(UTF-8)와 [토큰 정보](Token)를 리스트로 반환합니다.
+    ///
+    /// 문장 번호가 [Token::sent_position]과 일치하는 형태소들로 구성됩니다.
+    ///
+    /// `with_analyzed`를 `false`로 설정하여 분할했거나 index가 범위를 벗어난 경우 `None`을 반환합니다.
+    pub fn tokens(&self, index: usize) -> Result<Option<Vec<(String, Token)>>> {
+        let (Some(()), Some(analyzed)) = (self.check_index(index), self.analyzed.as_ref()) else {
+            return Ok(None);
+        };
+
+        let mut tokens = Vec::new();
+
+        for item in analyzed.iter() {
+            let (form, token) = item?;
+
+            if token.sent_position == index {
+                tokens.push((form, token));
+            }
+        }
+
+        Ok(Some(tokens))
+    }
+
+    /// 모든 문장의 (시작 위치, 끝 위치)를 리스트로 반환합니다.
+    pub fn to_vec(&self) -> Result<Vec<(usize, usize)>> {
+        let mut sents = Vec::with_capacity(self.size);
+
+        for i in 0..self.size {
+            sents.push((self.begin_unchecked(i)?, self.end_unchecked(i)?));
+        }
+
+        Ok(sents)
+    }
+
+    /// 모든 문장의 (시작 위치, 끝 위치)의 이터레이터 구조체를 반환합니다.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(self)
+    }
+}
+
+impl Drop for Sentences {
+    fn drop(&mut self) {
+        if KiwiRc::strong_count(&self.handle) > 1 {
+            return;
+        }
+
+        let res = unsafe { kiwi_ss_close(*self.handle) };
+
+        if res != 0 {
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `Sentences`: {}", err);
+            return;
+        }
+
+        tracing::trace!("closed `Sentences`");
+    }
+}
+
+pub struct Iter<'a> {
+    sentences: &'a Sentences,
+    i: usize,
+}
+
+impl<'a> Iter<'a> {
+    pub(crate) fn new(sentences: &'a Sentences) -> Self {
+        Self { sentences, i: 0 }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Result<(usize, usize)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.sentences.check_index(self.i)?;
+
+        let item = self
+            .sentences
+            .begin_unchecked(self.i)
+            .and_then(|begin| Ok((begin, self.sentences.end_unchecked(self.i)?)));
+
+        self.i += 1;
+
+        Some(item)
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    ffi::{c_char, c_int},
+    path::Path,
+};
+
+use parking_lot::Mutex;
+
+use crate::{
+    bindings::*, kiwi_error, path_to_cstring, text_to_cstring, Error, Kiwi, KiwiRc, Result,
+};
+
+/// 형태소 분석 결과를 기반으로 동작하는 서브워드 토크나이저
+///
+/// [Kiwi]로 텍스트를 형태소 단위로 분석한 뒤, 각 형태소를 서브워드 단위로 분할하여 토큰 id로 변환합니다.
+#[derive(Clone)]
+pub struct SwTokenizer {
+    pub(crate) handle: KiwiRc<Mutex<kiwi_swtokenizer_h>>,
+    /// 토크나이저가 사용하는 [Kiwi]. 먼저 해제되지 않도록 유지하며, 토크나이저가 분석하는 동안 읽기 잠금을 잡음
+    kiwi: Kiwi,
+}
+
+#[cfg(feature = "impl_send")]
+unsafe impl Send for SwTokenizer {}
+
+impl SwTokenizer {
+    /// 토크나이저 설정 파일을 불러와 [SwTokenizer]를 생성합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - 토크나이저 json 파일의 경로
+    /// * `kiwi` - 형태소 분석에 사용할 [Kiwi]
+    pub fn new(path: impl AsRef<Path>, kiwi: &Kiwi) -> Result<Self> {
+        let path = path.as_ref();
+
+        let c_path = path_to_cstring(path)?;
+
+        let handle = unsafe {
+            let kiwi_handle = kiwi.handle.read();
+            kiwi_swt_init(c_path.as_ptr(), *kiwi_handle)
+        };
+
+        if handle.is_null() {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::ModelLoad {
+                path: path.to_path_buf(),
+                message: err,
+            });
+        }
+
+        Ok(Self {
+            #[allow(clippy::arc_with_non_send_sync)]
+            handle: KiwiRc::new(Mutex::new(handle)),
+            kiwi: kiwi.clone(),
+        })
+    }
+
+    /// 텍스트를 토큰 id 목록으로 변환합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - 변환할 텍스트 (utf-8)
+    pub fn encode(&self, text: &str) -> Result<Vec<u32>> {
+        self.encode_inner(text, false)
+            .map(|(token_ids, _)| token_ids)
+    }
+
+    /// 텍스트를 토큰 id 목록으로 변환하고, 각 토큰이 원본 텍스트에서 차지하는 위치를 함께 반환합니다.
+    ///
+    /// # Parameters
+    ///
+    /// * `text` - 변환할 텍스트 (utf-8)
+    ///
+    /// # Return
+    ///
+    /// (토큰 id 목록, 각 토큰의 (시작 위치, 끝 위치) 목록)
+    ///
+    /// 위치는 UTF-8 문자열의 바이트 단위입니다.
+    pub fn encode_with_offsets(&self, text: &str) -> Result<(Vec<u32>, Vec<(usize, usize)>)> {
+        self.encode_inner(text, true)
+    }
+
+    fn encode_inner(
+        &self,
+        text: &str,
+        with_offsets: bool,
+    ) -> Result<(Vec<u32>, Vec<(usize, usize)>)> {
+        let text_size = text.len() as c_int;
+        let text = text_to_cstring(text);
+
+        // 토크나이저는 내부적으로 Kiwi의 분석기를 사용하므로, 옵션 설정과 겹치지 않도록 읽기 잠금을 함께 잡음
+        let _kiwi_handle = self.kiwi.handle.read();
+        let handle = self.handle.lock();
+
+        // 버퍼 없이 호출하여 필요한 토큰 개수를 먼저 구함
+        let size = unsafe {
+            kiwi_swt_encode(
+                *handle,
+                text.as_ptr(),
+                text_size,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+
+        if size < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        let mut token_ids = vec![0 as c_int; size as usize];
+        let mut offsets = if with_offsets {
+            vec![0 as c_int; size as usize * 2]
+        } else {
+            Vec::new()
+        };
+
+        let res = unsafe {
+            kiwi_swt_encode(
+                *handle,
+                text.as_ptr(),
+                text_size,
+                token_ids.as_mut_ptr(),
+                token_ids.len() as c_int,
+                if with_offsets {
+                    offsets.as_mut_ptr()
+                } else {
+                    std::ptr::null_mut()
+                },
+                offsets.len() as c_int,
+            )
+        };
+
+        if res < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        token_ids.truncate(res as usize);
+        offsets.truncate(res as usize * 2);
+
+        let token_ids = token_ids.into_iter().map(|id| id as u32).collect();
+        let offsets = offsets
+            .chunks_exact(2)
+            .map(|x| (x[0] as usize, x[1] as usize))
+            .collect();
+
+        Ok((token_ids, offsets))
+    }
+
+    /// 토큰 id 목록을 텍스트로 복원합니다.
+    ///
+    /// # Return
+    ///
+    /// UTF-8 인코딩된 문자열
+    pub fn decode(&self, token_ids: &[u32]) -> Result<String> {
+        let token_ids = token_ids.iter().map(|id| *id as c_int).collect::<Vec<_>>();
+
+        let _kiwi_handle = self.kiwi.handle.read();
+        let handle = self.handle.lock();
+
+        // 버퍼 없이 호출하여 필요한 버퍼 크기를 먼저 구함
+        let size = unsafe {
+            kiwi_swt_decode(
+                *handle,
+                token_ids.as_ptr(),
+                token_ids.len() as c_int,
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+
+        if size < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        // null 문자가 기록될 수 있도록 1바이트 여유를 둠
+        let mut buf = vec![0u8; size as usize + 1];
+
+        let res = unsafe {
+            kiwi_swt_decode(
+                *handle,
+                token_ids.as_ptr(),
+                token_ids.len() as c_int,
+                buf.as_mut_ptr() as *mut c_char,
+                buf.len() as c_int,
+            )
+        };
+
+        if res < 0 {
+            let err = kiwi_error().unwrap_or_default();
+            return Err(Error::Native(err));
+        }
+
+        buf.truncate((res as usize).min(size as usize));
+
+        String::from_utf8(buf)
+            .map_err(|err| Error::Native(format!("decoded text is not valid UTF-8: {}", err)))
+    }
+}
+
+impl Drop for SwTokenizer {
+    fn drop(&mut self) {
+        if KiwiRc::strong_count(&self.handle) > 1 {
+            return;
+        }
+
+        let res = unsafe {
+            let handle = self.handle.lock();
+            kiwi_swt_close(*handle)
+        };
+
+        if res != 0 {
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `SwTokenizer`: {}", err);
+            return;
+        }
+
+        tracing::trace!("closed `SwTokenizer`");
+    }
+}
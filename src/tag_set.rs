@@ -0,0 +1,265 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{Error, POSTag};
+
+/// 품사 태그의 집합
+///
+/// 불규칙 활용 태그를 포함한 모든 [POSTag]를 비트 하나씩으로 표현합니다.
+///
+/// VV와 VV-I는 서로 다른 태그로 취급하므로, 불규칙 활용 여부와 관계없이 확인하려면 [POSTag::base]를 사용하거나 `VV*`처럼 와일드카드로 생성해 주세요.
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::{POSTag, TagSet};
+///
+/// let tags: TagSet = "NN*,V*".parse().unwrap();
+///
+/// assert!(tags.contains(POSTag::NNG));
+/// assert!(tags.contains(POSTag::VVI));
+/// assert!(!tags.contains(POSTag::JKS));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TagSet([u64; 4]);
+
+impl TagSet {
+    /// 빈 집합
+    pub const EMPTY: TagSet = TagSet([0; 4]);
+
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// 정의된 모든 품사 태그를 포함하는 집합을 반환합니다.
+    pub fn all() -> Self {
+        POSTag::ALL.iter().copied().collect()
+    }
+
+    #[inline]
+    const fn position(tag: POSTag) -> (usize, u64) {
+        let num = tag.get_num() as usize;
+        (num / 64, 1 << (num % 64))
+    }
+
+    /// 태그를 추가합니다. 이미 포함되어 있던 경우 `false`를 반환합니다.
+    #[inline]
+    pub fn insert(&mut self, tag: POSTag) -> bool {
+        let (i, bit) = Self::position(tag);
+        let inserted = self.0[i] & bit == 0;
+        self.0[i] |= bit;
+        inserted
+    }
+
+    /// 태그를 제거합니다. 포함되어 있지 않았던 경우 `false`를 반환합니다.
+    #[inline]
+    pub fn remove(&mut self, tag: POSTag) -> bool {
+        let (i, bit) = Self::position(tag);
+        let removed = self.0[i] & bit != 0;
+        self.0[i] &= !bit;
+        removed
+    }
+
+    #[inline]
+    pub const fn contains(&self, tag: POSTag) -> bool {
+        let (i, bit) = Self::position(tag);
+        self.0[i] & bit != 0
+    }
+
+    #[inline]
+    pub const fn len(&self) -> usize {
+        (self.0[0].count_ones()
+            + self.0[1].count_ones()
+            + self.0[2].count_ones()
+            + self.0[3].count_ones()) as usize
+    }
+
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0[0] == 0 && self.0[1] == 0 && self.0[2] == 0 && self.0[3] == 0
+    }
+
+    #[inline]
+    pub fn union(&self, other: &TagSet) -> TagSet {
+        *self | *other
+    }
+
+    #[inline]
+    pub fn intersection(&self, other: &TagSet) -> TagSet {
+        *self & *other
+    }
+
+    #[inline]
+    pub fn difference(&self, other: &TagSet) -> TagSet {
+        *self - *other
+    }
+
+    /// 포함된 태그를 번호 순서대로 반환합니다.
+    pub fn iter(&self) -> Iter {
+        Iter { set: *self, num: 0 }
+    }
+}
+
+impl FromIterator<POSTag> for TagSet {
+    fn from_iter<T: IntoIterator<Item = POSTag>>(iter: T) -> Self {
+        let mut set = TagSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<POSTag> for TagSet {
+    fn extend<T: IntoIterator<Item = POSTag>>(&mut self, iter: T) {
+        for tag in iter {
+            self.insert(tag);
+        }
+    }
+}
+
+impl From<POSTag> for TagSet {
+    fn from(tag: POSTag) -> Self {
+        let mut set = TagSet::new();
+        set.insert(tag);
+        set
+    }
+}
+
+impl<const N: usize> From<[POSTag; N]> for TagSet {
+    fn from(tags: [POSTag; N]) -> Self {
+        tags.into_iter().collect()
+    }
+}
+
+macro_rules! impl_set_op {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident, |$a:ident, $b:ident| $expr:expr) => {
+        impl $op for TagSet {
+            type Output = TagSet;
+
+            fn $fn(self, rhs: TagSet) -> TagSet {
+                let mut words = [0; 4];
+                for (i, word) in words.iter_mut().enumerate() {
+                    let ($a, $b) = (self.0[i], rhs.0[i]);
+                    *word = $expr;
+                }
+                TagSet(words)
+            }
+        }
+
+        impl $assign_op for TagSet {
+            fn $assign_fn(&mut self, rhs: TagSet) {
+                *self = $op::$fn(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_set_op!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+impl_set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+impl_set_op!(Sub, sub, SubAssign, sub_assign, |a, b| a & !b);
+
+/// 정의된 모든 품사 태그 중 집합에 포함되지 않은 태그의 집합을 반환합니다.
+impl Not for TagSet {
+    type Output = TagSet;
+
+    fn not(self) -> TagSet {
+        TagSet::all() - self
+    }
+}
+
+/// 쉼표로 구분된 품사 태그 목록으로부터 생성합니다.
+///
+/// `*`로 끝나는 항목은 해당 접두어로 시작하는 모든 태그를 의미합니다. (`NN*` -> NNG, NNP, NNB)
+impl FromStr for TagSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = TagSet::new();
+
+        for item in s.split(',').map(str::trim).filter(|item| !item.is_empty()) {
+            match item.strip_suffix('*') {
+                Some(prefix) => {
+                    let mut matched = POSTag::ALL
+                        .iter()
+                        .copied()
+                        .filter(|tag| tag.as_str().starts_with(prefix))
+                        .peekable();
+
+                    if matched.peek().is_none() {
+                        return Err(Error::InvalidTag(item.to_owned()));
+                    }
+
+                    set.extend(matched);
+                }
+                None => {
+                    set.insert(item.parse()?);
+                }
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+/// 쉼표로 구분된 품사 태그 목록으로 출력합니다.
+impl Display for TagSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, tag) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(tag.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Debug for TagSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl IntoIterator for TagSet {
+    type Item = POSTag;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &TagSet {
+    type Item = POSTag;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// [TagSet::iter] 참고
+pub struct Iter {
+    set: TagSet,
+    num: usize,
+}
+
+impl Iterator for Iter {
+    type Item = POSTag;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.num <= u8::MAX as usize {
+            let tag = POSTag(self.num as u8);
+            self.num += 1;
+
+            if self.set.contains(tag) {
+                return Some(tag);
+            }
+        }
+
+        None
+    }
+}
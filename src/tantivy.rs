@@ -0,0 +1,214 @@
+use tantivy_tokenizer_api::{Token as TantivyToken, TokenStream, Tokenizer};
+
+use crate::{Kiwi, Lemmatizer, Match, POSTag, TagSet};
+
+/// [KiwiTokenizer]가 출력할 토큰의 텍스트
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenText {
+    /// 형태소의 형태 (예: 먹, 었, 다)
+    #[default]
+    Form,
+    /// 원본 텍스트에서 형태소가 차지하는 부분
+    Surface,
+    /// 용언의 경우 기본형, 그 외에는 형태 (예: 먹다)
+    ///
+    /// [Lemmatizer] 참고
+    Lemma,
+}
+
+/// tantivy에서 사용할 수 있는 형태소 분석 토크나이저
+///
+/// 토큰의 위치(position)는 어절 번호(word_position)이므로, 같은 어절에 속한 형태소들은 같은 위치를 가집니다.
+/// offset은 UTF-8 바이트 기준입니다.
+///
+/// 분석에 실패한 경우 에러를 기록하고 토큰을 반환하지 않습니다.
+///
+/// # Example
+///
+/// ```rust
+/// use rkiwi::{KiwiBuilder, KiwiTokenizer, TokenText};
+/// use tantivy_tokenizer_api::{TokenStream, Tokenizer};
+///
+/// let kiwi = KiwiBuilder::new(None, Default::default())
+///     .unwrap()
+///     .build(None, None)
+///     .unwrap();
+///
+/// let mut tokenizer = KiwiTokenizer::new(kiwi)
+///     .tags("NN*,V*".parse().unwrap())
+///     .text(TokenText::Lemma);
+///
+/// let mut stream = tokenizer.token_stream("밥을 먹었다");
+///
+/// assert_eq!(stream.next().unwrap().text, "밥");
+/// assert_eq!(stream.next().unwrap().text, "먹다");
+/// ```
+#[derive(Clone)]
+pub struct KiwiTokenizer {
+    kiwi: Kiwi,
+    match_options: Match,
+    tags: TagSet,
+    text: TokenText,
+    compound_nouns: bool,
+}
+
+impl KiwiTokenizer {
+    /// 기본적으로 조사, 어미, 부호를 제외한 형태소의 형태를 출력합니다.
+    pub fn new(kiwi: Kiwi) -> Self {
+        let tags = TagSet::all()
+            .iter()
+            .filter(|tag| !(tag.is_josa() || tag.is_eomi() || tag.is_symbol()))
+            .collect();
+
+        Self {
+            kiwi,
+            match_options: Match::new(),
+            tags,
+            text: TokenText::default(),
+            compound_nouns: false,
+        }
+    }
+
+    /// [Match] 참고
+    pub fn match_options(mut self, r: Match) -> Self {
+        self.match_options = r;
+        self
+    }
+
+    /// 출력할 형태소의 품사 태그를 설정합니다.
+    pub fn tags(mut self, r: TagSet) -> Self {
+        self.tags = r;
+        self
+    }
+
+    /// [TokenText] 참고
+    pub fn text(mut self, r: TokenText) -> Self {
+        self.text = r;
+        self
+    }
+
+    /// `true`로 설정 시 공백 없이 연속된 NNG, NNP 형태소를 이어붙인 복합 명사를 구성 명사들과 함께 출력합니다.
+    ///
+    /// 복합 명사는 구성 명사들과 같은 위치에 놓이므로, 복합 명사와 구성 명사 어느 쪽으로 검색해도 찾을 수 있습니다.
+    ///
+    /// 형태소분석기 -> 형태소분석기, 형태소, 분석기
+    pub fn compound_nouns(mut self, r: bool) -> Self {
+        self.compound_nouns = r;
+        self
+    }
+
+    fn tokenize(&self, text: &str) -> crate::Result<Vec<TantivyToken>> {
+        let analyzed = self.kiwi.analyze(text, 1, self.match_options, None, None)?;
+        let morphs = analyzed.to_vec()?;
+
+        let lemmas = match self.text {
+            TokenText::Lemma => Some(Lemmatizer::new().lemmatize(&morphs)),
+            _ => None,
+        };
+
+        let mut tokens: Vec<TantivyToken> = Vec::with_capacity(morphs.len());
+        // tokens 끝에 있는, 복합 명사로 병합할 명사의 개수
+        let mut compound = 0;
+
+        for (i, (form, token)) in morphs.iter().enumerate() {
+            if !self.tags.contains(token.tag) {
+                push_compound(&mut tokens, &mut compound);
+                continue;
+            }
+
+            if self.compound_nouns && matches!(token.tag, POSTag::NNG | POSTag::NNP) {
+                let adjacent = compound > 0
+                    && tokens
+                        .last()
+                        .is_some_and(|last| last.offset_to == token.byte_begin);
+
+                if !adjacent {
+                    push_compound(&mut tokens, &mut compound);
+                }
+
+                compound += 1;
+            } else {
+                push_compound(&mut tokens, &mut compound);
+            }
+
+            let text = match (self.text, &lemmas) {
+                (TokenText::Surface, _) => text[token.byte_begin..token.byte_end].to_owned(),
+                (TokenText::Lemma, Some(lemmas)) => lemmas[i].form.clone(),
+                _ => form.clone(),
+            };
+
+            tokens.push(TantivyToken {
+                offset_from: token.byte_begin,
+                offset_to: token.byte_end,
+                position: token.word_position,
+                text,
+                position_length: 1,
+            });
+        }
+
+        push_compound(&mut tokens, &mut compound);
+
+        Ok(tokens)
+    }
+}
+
+/// 병합할 명사가 두 개 이상인 경우 이어붙인 복합 명사 토큰을 구성 명사들 앞에 끼워넣습니다.
+fn push_compound(tokens: &mut Vec<TantivyToken>, compound: &mut usize) {
+    if *compound >= 2 {
+        let parts = &tokens[tokens.len() - *compound..];
+
+        let token = TantivyToken {
+            offset_from: parts[0].offset_from,
+            offset_to: parts[parts.len() - 1].offset_to,
+            position: parts[0].position,
+            text: parts.iter().map(|part| part.text.as_str()).collect(),
+            position_length: 1,
+        };
+
+        tokens.insert(tokens.len() - *compound, token);
+    }
+
+    *compound = 0;
+}
+
+impl Tokenizer for KiwiTokenizer {
+    type TokenStream<'a> = KiwiTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let tokens = self.tokenize(text).unwrap_or_else(|err| {
+            tracing::error!("failed to tokenize text: {}", err);
+            Vec::new()
+        });
+
+        KiwiTokenStream {
+            tokens: tokens.into_iter(),
+            token: TantivyToken::default(),
+        }
+    }
+}
+
+/// [KiwiTokenizer::token_stream](Tokenizer::token_stream) 참고
+pub struct KiwiTokenStream {
+    tokens: std::vec::IntoIter<TantivyToken>,
+    token: TantivyToken,
+}
+
+impl TokenStream for KiwiTokenStream {
+    fn advance(&mut self) -> bool {
+        match self.tokens.next() {
+            Some(token) => {
+                self.token = token;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn token(&self) -> &TantivyToken {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut TantivyToken {
+        &mut self.token
+    }
+}
@@ -1,11 +1,96 @@
 use std::{
+    any::Any,
     ffi::{c_char, c_int, c_void, CStr, CString},
-    str::FromStr,
+    panic::{catch_unwind, AssertUnwindSafe},
 };
 
 use widestring::{U16CString, U16String};
 
-use crate::bindings::kchar16_t;
+use crate::{
+    bindings::{kchar16_t, kiwi_res_close, kiwi_res_h},
+    text_to_cstring, text_to_u16cstring, to_cstring, to_u16cstring, Error, Result,
+};
+
+/// 네이티브 라이브러리에 `user_data`로 전달되는 콜백
+///
+/// 콜백에서 발생한 panic이 C++ 경계를 넘어 전파되지 않도록 trampoline에서 잡아 payload를 보관하고,
+/// 네이티브 함수가 반환된 뒤 [Callback::finish]로 [Error::CallbackPanic]을 반환합니다.
+///
+/// 사전에 추가될 문자열을 반환하는 콜백이 null 문자가 포함된 문자열을 반환한 경우에도 같은 방식으로 [Error::InvalidInput]을 반환합니다.
+///
+/// panic 또는 에러가 발생한 이후에는 콜백을 더 이상 호출하지 않습니다.
+pub(crate) struct Callback<F> {
+    f: F,
+    panic: Option<Box<dyn Any + Send>>,
+    error: Option<Error>,
+}
+
+impl<F> Callback<F> {
+    pub(crate) fn new(f: F) -> Self {
+        Self {
+            f,
+            panic: None,
+            error: None,
+        }
+    }
+
+    #[inline]
+    fn failed(&self) -> bool {
+        self.panic.is_some() || self.error.is_some()
+    }
+
+    /// 콜백을 호출합니다. panic 또는 에러가 발생했거나 이미 발생한 경우 `None`을 반환합니다.
+    #[inline]
+    fn call<R>(&mut self, call: impl FnOnce(&mut F) -> R) -> Option<R> {
+        if self.failed() {
+            return None;
+        }
+
+        match catch_unwind(AssertUnwindSafe(|| call(&mut self.f))) {
+            Ok(r) => Some(r),
+            Err(payload) => {
+                self.panic = Some(payload);
+                None
+            }
+        }
+    }
+
+    /// 콜백의 반환 값을 변환한 결과가 에러인 경우 기록하고 `None`을 반환합니다.
+    #[inline]
+    fn check<T>(&mut self, res: Result<T>) -> Option<T> {
+        match res {
+            Ok(r) => Some(r),
+            Err(err) => {
+                self.error = Some(err);
+                None
+            }
+        }
+    }
+
+    /// 콜백에서 panic이 발생한 경우 [Error::CallbackPanic]을, 콜백의 반환 값이 올바르지 않은 경우 해당 에러를 반환합니다.
+    pub(crate) fn finish(self) -> Result<()> {
+        if let Some(payload) = self.panic {
+            return Err(Error::CallbackPanic(panic_message(&*payload)));
+        }
+
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    "Box<dyn Any>".to_owned()
+}
 
 pub(crate) extern "C" fn replacer_trampoline<F>(
     input: *const c_char,
@@ -16,25 +101,18 @@ pub(crate) extern "C" fn replacer_trampoline<F>(
 where
     F: FnMut(&str) -> String,
 {
-    // FIXME: remove unwrap
-    let input = unsafe { CStr::from_ptr(input) }.to_str().unwrap();
-
-    let replacer = unsafe { &mut *(replacer as *mut Box<F>) };
+    let input = unsafe { CStr::from_ptr(input) };
 
-    let res = CString::from_str(&replacer(input)).unwrap();
-    let len = res.as_bytes().len();
+    let replacer = unsafe { &mut *(replacer as *mut Callback<F>) };
 
-    if ret.is_null() {
-        return len as c_int;
-    }
+    // 변형 결과는 형태소로 추가되므로 null 문자를 치환하지 않고 에러로 처리함
+    // panic 또는 에러가 발생한 경우 입력을 그대로 반환하여 변형이 일어나지 않도록 함
+    let res = replacer
+        .call(|replacer| replacer(&input.to_string_lossy()))
+        .and_then(|res| replacer.check(to_cstring(&res)))
+        .unwrap_or_else(|| input.to_owned());
 
-    unsafe {
-        // memcpy
-        // src dest 메모리 영역이 겹치지 않음
-        std::ptr::copy_nonoverlapping(res.as_ptr(), ret, len);
-    }
-
-    len as c_int
+    write_reader_result(&res, ret)
 }
 
 pub(crate) extern "C" fn reader_trampoline<F>(
@@ -45,9 +123,98 @@ pub(crate) extern "C" fn reader_trampoline<F>(
 where
     F: FnMut(i32) -> String,
 {
-    let reader = unsafe { &mut *(reader as *mut Box<F>) };
+    let reader = unsafe { &mut *(reader as *mut Callback<F>) };
 
-    let res = CString::from_str(&reader(idx)).unwrap();
+    // 추출한 단어는 사전에 추가될 수 있으므로 null 문자를 치환하지 않고 에러로 처리함
+    // panic 또는 에러가 발생한 경우 빈 문자열을 반환하여 입력을 끝냄
+    let res = reader
+        .call(|reader| reader(idx))
+        .and_then(|res| reader.check(to_cstring(&res)))
+        .unwrap_or_default();
+
+    write_reader_result(&res, ret)
+}
+
+pub(crate) extern "C" fn reader_w_trampoline<F>(
+    idx: c_int,
+    ret: *mut kchar16_t,
+    reader_w: *mut c_void,
+) -> c_int
+where
+    F: FnMut(i32) -> U16String,
+{
+    let reader_w = unsafe { &mut *(reader_w as *mut Callback<F>) };
+
+    let res = reader_w
+        .call(|reader_w| reader_w(idx))
+        .and_then(|res| reader_w.check(to_u16cstring(&res)))
+        .unwrap_or_default();
+
+    write_reader_w_result(&res, ret)
+}
+
+/// `kiwi_analyze_m`, `kiwi_analyze_mw`에 `user_data`로 전달되는 reader와 receiver의 묶음
+pub(crate) struct MultiAnalyze<R, C> {
+    pub(crate) reader: R,
+    pub(crate) receiver: C,
+}
+
+pub(crate) extern "C" fn multi_reader_trampoline<R, C>(
+    idx: c_int,
+    ret: *mut c_char,
+    user_data: *mut c_void,
+) -> c_int
+where
+    R: FnMut(i32) -> String,
+    C: FnMut(usize, kiwi_res_h),
+{
+    let multi = unsafe { &mut *(user_data as *mut Callback<MultiAnalyze<R, C>>) };
+
+    // 분석할 텍스트이므로 null 문자는 공백으로 치환함
+    let res = multi.call(|multi| (multi.reader)(idx)).unwrap_or_default();
+
+    write_reader_result(&text_to_cstring(&res), ret)
+}
+
+pub(crate) extern "C" fn multi_reader_w_trampoline<R, C>(
+    idx: c_int,
+    ret: *mut kchar16_t,
+    user_data: *mut c_void,
+) -> c_int
+where
+    R: FnMut(i32) -> U16String,
+    C: FnMut(usize, kiwi_res_h),
+{
+    let multi = unsafe { &mut *(user_data as *mut Callback<MultiAnalyze<R, C>>) };
+
+    let res = multi.call(|multi| (multi.reader)(idx)).unwrap_or_default();
+
+    write_reader_w_result(&text_to_u16cstring(&res), ret)
+}
+
+pub(crate) extern "C" fn multi_receiver_trampoline<R, C>(
+    idx: c_int,
+    res: kiwi_res_h,
+    user_data: *mut c_void,
+) -> c_int
+where
+    C: FnMut(usize, kiwi_res_h),
+{
+    let multi = unsafe { &mut *(user_data as *mut Callback<MultiAnalyze<R, C>>) };
+
+    // 이미 panic이 발생하여 receiver가 호출되지 않은 경우 결과를 직접 해제함
+    if multi.failed() {
+        unsafe { kiwi_res_close(res) };
+        return 0;
+    }
+
+    multi.call(|multi| (multi.receiver)(idx as usize, res));
+
+    0
+}
+
+#[inline]
+fn write_reader_result(res: &CString, ret: *mut c_char) -> c_int {
     let len = res.as_bytes().len();
 
     if ret.is_null() {
@@ -63,17 +230,8 @@ where
     len as c_int
 }
 
-pub(crate) extern "C" fn reader_w_trampoline<F>(
-    idx: c_int,
-    ret: *mut kchar16_t,
-    reader_w: *mut c_void,
-) -> c_int
-where
-    F: FnMut(i32) -> U16String,
-{
-    let reader_w = unsafe { &mut *(reader_w as *mut Box<F>) };
-
-    let res = U16CString::from_ustr(reader_w(idx)).unwrap();
+#[inline]
+fn write_reader_w_result(res: &U16CString, ret: *mut kchar16_t) -> c_int {
     let len = res.len();
 
     if ret.is_null() {
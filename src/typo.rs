@@ -1,8 +1,6 @@
-use std::{ffi::CString, str::FromStr};
-
 use parking_lot::Mutex;
 
-use crate::{bindings::*, kiwi_error, Error, KiwiRc, Result};
+use crate::{bindings::*, kiwi_error, to_cstring, Error, KiwiRc, Result};
 
 pub(crate) mod sealed {
     use std::borrow::Cow;
@@ -105,6 +103,9 @@ impl Default for CondVowel {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum DefaultTypoSet {
     WithoutTypo,
     BasicTypoSet,
@@ -191,13 +192,9 @@ impl TypoTransformer {
         cost: f32,
         condition: impl Into<Option<CondVowel>>,
     ) -> Result<()> {
-        let originals = originals
-            .map(|s| CString::from_str(s).unwrap())
-            .collect::<Vec<_>>();
+        let originals = originals.map(to_cstring).collect::<Result<Vec<_>>>()?;
         let mut originals = originals.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
-        let errors = errors
-            .map(|s| CString::from_str(s).unwrap())
-            .collect::<Vec<_>>();
+        let errors = errors.map(to_cstring).collect::<Result<Vec<_>>>()?;
         let mut errors = errors.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
 
         let condition = match condition.into() {
@@ -220,7 +217,7 @@ impl TypoTransformer {
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::TypoDefinition(err));
         }
 
         Ok(())
@@ -238,7 +235,7 @@ impl TypoTransformer {
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::TypoDefinition(err));
         }
 
         Ok(())
@@ -252,7 +249,7 @@ impl TypoTransformer {
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::TypoDefinition(err));
         }
 
         Ok(())
@@ -266,7 +263,7 @@ impl TypoTransformer {
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::TypoDefinition(err));
         }
 
         Ok(())
@@ -280,7 +277,7 @@ impl TypoTransformer {
 
         if res != 0 {
             let err = kiwi_error().unwrap_or_default();
-            return Err(Error::Native(err));
+            return Err(Error::TypoDefinition(err));
         }
 
         Ok(())
@@ -299,7 +296,9 @@ impl Drop for TypoTransformer {
         };
 
         if res != 0 {
-            panic!("{}", kiwi_error().unwrap_or_default());
+            let err = kiwi_error().unwrap_or_default();
+            tracing::error!("failed to close `TypoTransformer`: {}", err);
+            return;
         }
 
         tracing::trace!("closed `TypoTransformer`");
@@ -0,0 +1,391 @@
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+};
+
+use crate::{Analyzed, Error, Lemmatizer, POSTag, Result};
+
+macro_rules! impl_upos {
+    ($(
+        $(#[$docs:meta])*
+        $name:ident,
+    )*) => {
+        /// Universal Dependencies의 품사 태그 (UPOS)
+        ///
+        /// <https://universaldependencies.org/u/pos/>
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[allow(clippy::upper_case_acronyms)]
+        pub enum UPos {
+            $(
+                $(#[$docs])*
+                $name,
+            )*
+        }
+
+        impl UPos {
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(
+                        UPos::$name => stringify!($name),
+                    )*
+                }
+            }
+        }
+
+        impl FromStr for UPos {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                match s {
+                    $(
+                        stringify!($name) => Ok(UPos::$name),
+                    )*
+                    _ => Err(Error::InvalidTag(s.to_owned())),
+                }
+            }
+        }
+    };
+}
+
+impl_upos![
+    /// 형용사
+    ADJ,
+    /// 부치사 (조사)
+    ADP,
+    /// 부사
+    ADV,
+    /// 보조 용언, 지정사
+    AUX,
+    /// 등위 접속사
+    CCONJ,
+    /// 관형사
+    DET,
+    /// 감탄사
+    INTJ,
+    /// 명사
+    NOUN,
+    /// 수사
+    NUM,
+    /// 불변화사 (어미 등)
+    PART,
+    /// 대명사
+    PRON,
+    /// 고유 명사
+    PROPN,
+    /// 구두점
+    PUNCT,
+    /// 종속 접속사 (연결 어미)
+    SCONJ,
+    /// 기호
+    SYM,
+    /// 동사
+    VERB,
+    /// 기타
+    X,
+];
+
+impl Display for UPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// [UPos::as_str]의 문자열로 직렬화합니다.
+#[cfg(feature = "serde")]
+impl serde::Serialize for UPos {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UPos {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl POSTag {
+    /// 대응하는 Universal Dependencies 품사 태그를 반환합니다.
+    ///
+    /// 불규칙 활용 여부는 무시하며, 대응하는 태그가 없는 W_, Z_, USER 태그 등은 [UPos::X] 또는 [UPos::SYM]이 됩니다.
+    pub const fn to_upos(&self) -> UPos {
+        match self.base() {
+            POSTag::NNG | POSTag::NNB | POSTag::XSN | POSTag::XR => UPos::NOUN,
+            POSTag::NNP | POSTag::W_MENTION | POSTag::W_HASHTAG => UPos::PROPN,
+            POSTag::NP => UPos::PRON,
+            POSTag::NR | POSTag::SN | POSTag::W_SERIAL => UPos::NUM,
+            POSTag::VV | POSTag::XSV | POSTag::P => UPos::VERB,
+            POSTag::VA | POSTag::XSA | POSTag::PA => UPos::ADJ,
+            POSTag::VX | POSTag::VCP | POSTag::VCN => UPos::AUX,
+            POSTag::MM => UPos::DET,
+            POSTag::MAG | POSTag::XSM => UPos::ADV,
+            POSTag::MAJ | POSTag::JC => UPos::CCONJ,
+            POSTag::IC => UPos::INTJ,
+            POSTag::EC => UPos::SCONJ,
+            POSTag::EP | POSTag::EF | POSTag::ETN | POSTag::ETM => UPos::PART,
+            POSTag::SF
+            | POSTag::SP
+            | POSTag::SS
+            | POSTag::SSO
+            | POSTag::SSC
+            | POSTag::SE
+            | POSTag::SO
+            | POSTag::SB => UPos::PUNCT,
+            POSTag::SW | POSTag::W_EMOJI => UPos::SYM,
+            tag if tag.is_josa() => UPos::ADP,
+            _ => UPos::X,
+        }
+    }
+
+    /// Universal Dependencies 형태 자질(FEATS)을 반환합니다. 해당하는 자질이 없으면 `None`을 반환합니다.
+    ///
+    /// `Name=Value` 형식의 자질 하나를 반환합니다.
+    pub const fn ud_feats(&self) -> Option<&'static str> {
+        let feats = match self.base() {
+            POSTag::NP => "PronType=Prs",
+            POSTag::VCN => "Polarity=Neg",
+            POSTag::SL | POSTag::SH => "Foreign=Yes",
+            POSTag::SSO | POSTag::SSC | POSTag::SS => "PunctType=Brck",
+            POSTag::SF => "PunctType=Peri",
+            POSTag::SP => "PunctType=Comm",
+            POSTag::ETN => "VerbForm=Ger",
+            POSTag::ETM => "VerbForm=Part",
+            POSTag::EF => "VerbForm=Fin",
+            POSTag::EC => "VerbForm=Conv",
+            _ => return None,
+        };
+
+        Some(feats)
+    }
+
+    /// Universal Dependencies 품사 태그로부터 대표하는 품사 태그를 반환합니다.
+    ///
+    /// 하나의 태그로 정할 수 없는 [UPos::ADP], [UPos::PART], [UPos::SCONJ], [UPos::CCONJ], [UPos::X]는 `None`을 반환합니다.
+    pub const fn from_upos(upos: UPos) -> Option<POSTag> {
+        let tag = match upos {
+            UPos::NOUN => POSTag::NNG,
+            UPos::PROPN => POSTag::NNP,
+            UPos::PRON => POSTag::NP,
+            UPos::NUM => POSTag::NR,
+            UPos::VERB => POSTag::VV,
+            UPos::ADJ => POSTag::VA,
+            UPos::AUX => POSTag::VX,
+            UPos::DET => POSTag::MM,
+            UPos::ADV => POSTag::MAG,
+            UPos::INTJ => POSTag::IC,
+            UPos::PUNCT => POSTag::SF,
+            UPos::SYM => POSTag::SW,
+            UPos::ADP | UPos::PART | UPos::SCONJ | UPos::CCONJ | UPos::X => return None,
+        };
+
+        Some(tag)
+    }
+
+    /// 대응하는 세종 품사 태그를 반환합니다.
+    ///
+    /// 세종 태그셋에 없는 태그는 다음과 같이 변환됩니다.
+    ///
+    /// * 불규칙 활용 태그 -> 기본 태그 (VV-I -> VV)
+    /// * SSO, SSC -> SS, SB -> SW, XSM -> XSN
+    /// * W_URL, W_EMAIL -> SL, W_MENTION, W_HASHTAG -> NNP, W_SERIAL -> SN, W_EMOJI -> SW
+    /// * P, PA -> NV (용언 추정 범주)
+    /// * UNK, Z_CODA, Z_SIOT, USER0 ~ USER4 -> NA (분석 불능 범주)
+    pub const fn to_sejong(&self) -> &'static str {
+        match self.base() {
+            POSTag::SSO | POSTag::SSC => "SS",
+            POSTag::SB | POSTag::W_EMOJI => "SW",
+            POSTag::XSM => "XSN",
+            POSTag::W_URL | POSTag::W_EMAIL => "SL",
+            POSTag::W_MENTION | POSTag::W_HASHTAG => "NNP",
+            POSTag::W_SERIAL => "SN",
+            POSTag::P | POSTag::PA => "NV",
+            POSTag::UNK | POSTag::Z_CODA | POSTag::Z_SIOT => "NA",
+            tag if tag.is_user() => "NA",
+            tag => tag.as_str(),
+        }
+    }
+
+    /// 세종 품사 태그로부터 품사 태그를 반환합니다.
+    ///
+    /// NF, NV, NA는 [POSTag::UNK]가 됩니다.
+    ///
+    /// # Errors
+    ///
+    /// 세종 태그셋에 없는 태그인 경우 [Error::InvalidTag]를 반환합니다.
+    pub fn from_sejong(tag: &str) -> Result<POSTag> {
+        let tag = match tag {
+            "NF" | "NV" | "NA" => POSTag::UNK,
+            "NNG" => POSTag::NNG,
+            "NNP" => POSTag::NNP,
+            "NNB" => POSTag::NNB,
+            "NR" => POSTag::NR,
+            "NP" => POSTag::NP,
+            "VV" => POSTag::VV,
+            "VA" => POSTag::VA,
+            "VX" => POSTag::VX,
+            "VCP" => POSTag::VCP,
+            "VCN" => POSTag::VCN,
+            "MM" => POSTag::MM,
+            "MAG" => POSTag::MAG,
+            "MAJ" => POSTag::MAJ,
+            "IC" => POSTag::IC,
+            "JKS" => POSTag::JKS,
+            "JKC" => POSTag::JKC,
+            "JKG" => POSTag::JKG,
+            "JKO" => POSTag::JKO,
+            "JKB" => POSTag::JKB,
+            "JKV" => POSTag::JKV,
+            "JKQ" => POSTag::JKQ,
+            "JX" => POSTag::JX,
+            "JC" => POSTag::JC,
+            "EP" => POSTag::EP,
+            "EF" => POSTag::EF,
+            "EC" => POSTag::EC,
+            "ETN" => POSTag::ETN,
+            "ETM" => POSTag::ETM,
+            "XPN" => POSTag::XPN,
+            "XSN" => POSTag::XSN,
+            "XSV" => POSTag::XSV,
+            "XSA" => POSTag::XSA,
+            "XR" => POSTag::XR,
+            "SF" => POSTag::SF,
+            "SP" => POSTag::SP,
+            "SS" => POSTag::SS,
+            "SE" => POSTag::SE,
+            "SO" => POSTag::SO,
+            "SW" => POSTag::SW,
+            "SL" => POSTag::SL,
+            "SH" => POSTag::SH,
+            "SN" => POSTag::SN,
+            _ => return Err(Error::InvalidTag(tag.to_owned())),
+        };
+
+        Ok(tag)
+    }
+}
+
+impl Analyzed {
+    /// index번째 분석 결과를 CoNLL-U 형식으로 변환합니다.
+    ///
+    /// 문장마다 `# sent_id`, `# text` 주석을 붙이고, 어절은 여러 형태소로 이루어진 경우 `1-2`와 같은 범위 줄로 나타냅니다.
+    /// 형태소 줄의 LEMMA는 [Lemmatizer]로 복원한 기본형, UPOS와 FEATS는 [POSTag::to_upos], [POSTag::ud_feats], XPOS는 Kiwi의 품사 태그입니다.
+    /// 의존 구문 정보는 제공하지 않으므로 HEAD, DEPREL, DEPS는 `_`입니다.
+    ///
+    /// # Return
+    ///
+    /// index가 범위를 벗어난 경우 `None`을 반환합니다.
+    pub fn to_conllu(&self, index: usize) -> Result<Option<String>> {
+        let Some(word_num) = self.word_num(index) else {
+            return Ok(None);
+        };
+
+        let mut tokens = Vec::with_capacity(word_num);
+
+        for j in 0..word_num {
+            // word_num 범위 내에서는 항상 값이 존재함
+            if let (Some(form), Some(token)) = (self.form(index, j)?, self.token(index, j)?) {
+                tokens.push((form, token));
+            }
+        }
+
+        let lemmas = Lemmatizer::new().lemmatize(&tokens);
+        let text = self.text();
+
+        let mut out = String::new();
+        let mut sent_id = 0;
+        let mut i = 0;
+
+        while i < tokens.len() {
+            let sent_position = tokens[i].1.sent_position;
+            let sent_end = tokens[i..]
+                .iter()
+                .position(|(_, token)| token.sent_position != sent_position)
+                .map_or(tokens.len(), |n| i + n);
+            let sentence = &tokens[i..sent_end];
+
+            sent_id += 1;
+            let begin = sentence[0].1.byte_begin;
+            let end = sentence[sentence.len() - 1].1.byte_end;
+
+            // String에 쓰는 경우 실패하지 않음
+            let _ = writeln!(out, "# sent_id = {}", sent_id);
+            let _ = writeln!(out, "# text = {}", text[begin..end].replace('\n', " "));
+
+            let mut id = 1;
+            let mut j = 0;
+
+            while j < sentence.len() {
+                let word_position = sentence[j].1.word_position;
+                let word_end = sentence[j..]
+                    .iter()
+                    .position(|(_, token)| token.word_position != word_position)
+                    .map_or(sentence.len(), |n| j + n);
+                let word = &sentence[j..word_end];
+
+                let begin = word[0].1.byte_begin;
+                let end = word[word.len() - 1].1.byte_end;
+                let space_after = match sentence.get(word_end) {
+                    Some((_, next)) if next.byte_begin == end => "SpaceAfter=No",
+                    _ => "_",
+                };
+
+                if word.len() > 1 {
+                    let _ = writeln!(
+                        out,
+                        "{}-{}\t{}\t_\t_\t_\t_\t_\t_\t_\t{}",
+                        id,
+                        id + word.len() - 1,
+                        &text[begin..end],
+                        space_after
+                    );
+                }
+
+                for (k, (form, token)) in word.iter().enumerate() {
+                    // UD는 자질을 이름순으로 정렬하도록 규정함
+                    let mut feats: Vec<&str> = token.tag.ud_feats().into_iter().collect();
+                    if token.typo_cost > 0.0 {
+                        feats.push("Typo=Yes");
+                    }
+                    feats.sort_unstable();
+
+                    let feats = if feats.is_empty() {
+                        "_".to_owned()
+                    } else {
+                        feats.join("|")
+                    };
+                    let misc = if word.len() == 1 { space_after } else { "_" };
+
+                    let _ = writeln!(
+                        out,
+                        "{}\t{}\t{}\t{}\t{}\t{}\t_\t_\t_\t{}",
+                        id,
+                        form,
+                        lemmas[i + j + k].form,
+                        token.tag.to_upos(),
+                        token.tag,
+                        feats,
+                        misc
+                    );
+
+                    id += 1;
+                }
+
+                j = word_end;
+            }
+
+            out.push('\n');
+            i = sent_end;
+        }
+
+        Ok(Some(out))
+    }
+}
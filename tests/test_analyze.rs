@@ -2,6 +2,7 @@ use std::time::Instant;
 
 use rkiwi::{kiwi_version, KiwiBuilder, KiwiOptions, Match, POSTag};
 use tracing::Level;
+use widestring::U16String;
 
 fn tracing() {
     tracing_subscriber::fmt()
@@ -24,6 +25,69 @@ fn send_trait() {
     assert_send::<rkiwi::Pretokenized>();
 }
 
+#[test]
+fn kiwi_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<rkiwi::Kiwi>();
+}
+
+#[test]
+fn test_analyze_concurrently() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(None, KiwiOptions::default())?.build(None, None)?;
+
+    let texts = [
+        "안녕하세요 저는 바보입니다.",
+        "제 핏줄 보이시나요?",
+        "만둣국 먹고 싶다.",
+    ];
+
+    let expected = texts
+        .iter()
+        .map(|text| {
+            Ok(kiwi
+                .analyze(text, 1, Match::new(), None, None)?
+                .to_result()?)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    std::thread::scope(|s| {
+        let handles = (0..8)
+            .map(|i| {
+                let kiwi = &kiwi;
+                s.spawn(move || {
+                    if i == 0 {
+                        kiwi.set_space_penalty(kiwi.get_space_penalty());
+                    }
+
+                    texts
+                        .iter()
+                        .map(|text| {
+                            kiwi.analyze(text, 1, Match::new(), None, None)
+                                .unwrap()
+                                .to_result()
+                                .unwrap()
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), expected);
+        }
+    });
+
+    Ok(())
+}
+
+#[test]
+fn analysis_result_send_sync() {
+    fn assert_send_sync<T: Send + Sync + Clone>() {}
+
+    assert_send_sync::<rkiwi::AnalysisResult>();
+}
+
 #[tokio::test]
 async fn test_analyze() -> anyhow::Result<()> {
     use rkiwi::{DefaultTypoSet, TypoTransformer};
@@ -91,9 +155,10 @@ async fn test_analyze() -> anyhow::Result<()> {
     // })
     // .await??;
 
-    println!("{:?}", analyzed.to_vec());
+    println!("{:?}", analyzed.to_vec()?);
 
-    for (form, token) in analyzed.iter() {
+    for item in analyzed.iter() {
+        let (form, token) = item?;
         print!("{} {} / ", form, token.tag);
     }
     println!();
@@ -105,10 +170,136 @@ async fn test_analyze() -> anyhow::Result<()> {
 
     let analyzed = kiwi.analyze(text, 1, match_options, None, None)?;
 
-    for (form, token) in analyzed.iter() {
+    for i in 0..analyzed.word_num(0).unwrap() {
+        let token = analyzed.token(0, i)?.unwrap();
+
+        assert_eq!(
+            analyzed.surface(0, i)?,
+            Some(&text[token.byte_begin..token.byte_end])
+        );
+    }
+
+    let token = analyzed.token(0, 0)?.unwrap();
+    assert_eq!(analyzed.surface(0, 0)?, Some("에르핀"));
+    assert_eq!((token.byte_begin, token.byte_end), (0, "에르핀".len()));
+
+    let result = analyzed.to_result()?;
+
+    assert_eq!(result.candidates.len(), analyzed.size());
+    assert_eq!(
+        result.candidates[0].tokens.len(),
+        analyzed.word_num(0).unwrap()
+    );
+
+    for item in analyzed.iter() {
+        let (form, token) = item?;
         print!("{} {} / ", form, token.tag);
     }
     println!();
 
     Ok(())
 }
+
+#[test]
+fn test_split_into_sents() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "안녕하세요 저는 바보입니다. 제 핏줄 보이시나요? 만둣국 먹고 싶다.";
+
+    let sentences = kiwi.split_into_sents(text, Match::new(), true)?;
+
+    let expected = [
+        "안녕하세요 저는 바보입니다.",
+        "제 핏줄 보이시나요?",
+        "만둣국 먹고 싶다.",
+    ];
+
+    assert_eq!(sentences.size(), expected.len());
+
+    // UTF-8 바이트 위치이므로 원문을 그대로 잘라낼 수 있어야 함
+    for (i, item) in sentences.iter().enumerate() {
+        let (begin, end) = item?;
+
+        assert_eq!(begin, text.find(expected[i]).unwrap(), "{}", i);
+        assert_eq!(text[begin..end].trim_end(), expected[i], "{}", i);
+
+        let tokens = sentences.tokens(i)?.unwrap();
+        assert!(!tokens.is_empty());
+    }
+
+    assert_eq!(sentences.end(expected.len() - 1)?, Some(text.len()));
+
+    // UTF-16 입력은 글자 단위 위치를 반환함
+    let text_w = U16String::from_str(text);
+    let sentences_w = kiwi.split_into_sents_w(&text_w, Match::new(), false)?;
+
+    assert_eq!(sentences_w.size(), sentences.size());
+
+    for (item, item_w) in sentences.iter().zip(sentences_w.iter()) {
+        let (begin, end) = item?;
+        let (begin_w, end_w) = item_w?;
+
+        assert_eq!(text[..begin].encode_utf16().count(), begin_w);
+        assert_eq!(text[..end].encode_utf16().count(), end_w);
+    }
+
+    assert!(sentences.begin(sentences.size())?.is_none());
+
+    let sentences = kiwi.split_into_sents(text, Match::new(), false)?;
+
+    assert!(sentences.analyzed().is_none());
+    assert!(sentences.tokens(0)?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_analyze_many() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(2, KiwiOptions::default())?.build(None, None)?;
+
+    let texts = ["안녕하세요 저는 바보입니다.", "", "만둣국 먹고 싶다."];
+
+    let analyzed = kiwi.analyze_many(texts, 1, Match::new(), None)?;
+
+    assert_eq!(analyzed.len(), texts.len());
+
+    for ((i, analyzed), text) in analyzed.iter().zip(texts) {
+        let single = kiwi.analyze(text, 1, Match::new(), None, None)?;
+
+        assert_eq!(analyzed.to_vec()?.len(), single.to_vec()?.len(), "{}", i);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_find_morphemes() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?
+        .add_word("벨리타", POSTag::NNP, 0.0)?
+        .build(None, None)?;
+
+    let morphemes = kiwi.find_morphemes("벨리타", POSTag::NNP, None)?;
+
+    assert_eq!(morphemes.len(), 1);
+    assert_eq!(morphemes[0].form, "벨리타");
+    assert_eq!(morphemes[0].tag, POSTag::NNP);
+    assert_eq!(kiwi.morpheme(morphemes[0].id)?, morphemes[0]);
+
+    let morphemes = kiwi.find_morphemes("먹", None, None)?;
+
+    assert!(morphemes.iter().any(|x| x.tag == POSTag::VV));
+
+    let morphemes = kiwi.find_morphemes_with_prefix("벨리", None, None, 10)?;
+
+    assert!(morphemes.iter().any(|x| x.form == "벨리타"));
+
+    assert!(kiwi.find_morphemes("벨리타", POSTag::VV, None)?.is_empty());
+
+    let analyzed = kiwi.analyze("벨리타가 왔다.", 1, Match::new(), None, None)?;
+    let token = analyzed.token(0, 0)?.unwrap();
+
+    assert_eq!(analyzed.morpheme_id(0, 0)?, Some(token.morph_id));
+    assert_eq!(kiwi.morpheme(token.morph_id)?.form, "벨리타");
+
+    Ok(())
+}
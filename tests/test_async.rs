@@ -0,0 +1,70 @@
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use rkiwi::{AsyncKiwi, AsyncKiwiOptions, KiwiBuilder, Match, POSTag};
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_async_analyze() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(None, Default::default())?.build(None, None)?;
+    let kiwi = AsyncKiwi::new(kiwi, AsyncKiwiOptions::default().queue_size(4));
+
+    let texts: [&'static str; 3] = ["만둣국 먹고 싶다.", "", "오늘은 날씨가 좋다."];
+
+    let tasks = texts
+        .into_iter()
+        .map(|text| {
+            let kiwi = kiwi.clone();
+            tokio::spawn(async move { kiwi.analyze(text, 1, Match::new()).await })
+        })
+        .collect::<Vec<_>>();
+
+    for (text, task) in texts.iter().zip(tasks) {
+        let expected = kiwi.kiwi().analyze(text, 1, Match::new(), None, None)?;
+        let res = task.await??;
+
+        assert_eq!(res, expected.to_result()?);
+    }
+
+    let res = kiwi.analyze("만둣국", 1, Match::new()).await?;
+    assert_eq!(res.candidates[0].tokens[0].token.tag, POSTag::NNG);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_async_analyze_cancelled() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(None, Default::default())?.build(None, None)?;
+    let kiwi = AsyncKiwi::new(kiwi, AsyncKiwiOptions::default().workers(1));
+
+    // 하나뿐인 작업 스레드가 오래 걸리는 분석을 처리하는 동안 다음 작업은 대기열에 머무름
+    let slow = {
+        let kiwi = kiwi.clone();
+        let text = "만둣국 먹고 싶다. ".repeat(20000);
+        tokio::spawn(async move { kiwi.analyze(text, 1, Match::new()).await })
+    };
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let cancelled = {
+        let kiwi = kiwi.clone();
+        tokio::spawn(async move { kiwi.analyze("취소될 요청", 1, Match::new()).await })
+    };
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    // 대기열에 들어간 작업을 분석이 시작되기 전에 취소함
+    assert!(!slow.is_finished(), "the slow job must still be running");
+    cancelled.abort();
+
+    // 취소된 작업의 결과는 전달되지 않음
+    let err = cancelled.await.unwrap_err();
+    assert!(err.is_cancelled(), "{:?}", err);
+
+    // 작업 스레드는 취소된 작업을 건너뛰고 이후의 요청을 계속 처리함
+    let res = kiwi.analyze("만둣국 먹고 싶다.", 1, Match::new()).await?;
+    assert_eq!(res.candidates[0].tokens[0].form, "만둣국");
+
+    let res = slow.await??;
+    assert!(!res.candidates.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+
+use rkiwi::{Error, KiwiBuilder, KiwiOptions, Match, POSTag};
+
+/// 테스트마다 겹치지 않는 임시 폴더를 만들고, 해제될 때 지웁니다.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> std::io::Result<Self> {
+        let path = std::env::temp_dir().join(format!("rkiwi-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn test_load_dict() -> anyhow::Result<()> {
+    let dir = TempDir::new("load-dict")?;
+    let dict_path = dir.0.join("user.dict");
+    std::fs::write(&dict_path, "키윗키윗\tNNP\t0.0\n")?;
+
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?
+        .load_dict(dict_path.to_str().unwrap())?
+        .build(None, None)?;
+
+    let tokens = kiwi
+        .analyze("키윗키윗을 먹었다.", 1, Match::new(), None, None)?
+        .to_vec()?;
+
+    assert_eq!(tokens[0].0, "키윗키윗", "{:?}", tokens);
+    assert_eq!(tokens[0].1.tag, POSTag::NNP);
+
+    let err = KiwiBuilder::new(1, KiwiOptions::default())?
+        .load_dict(dir.0.join("not_found.dict").to_str().unwrap())
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::DictParse { .. }), "{:?}", err);
+
+    Ok(())
+}
+
+#[test]
+fn test_add_re_word() -> anyhow::Result<()> {
+    let text = "PROJ-1234 이슈를 해결했다.";
+
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+    let tokens = kiwi.analyze(text, 1, Match::new(), None, None)?.to_vec()?;
+
+    assert_ne!(tokens[0].0, "PROJ-1234", "{:?}", tokens);
+
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?
+        .add_re_word(r"PROJ-[0-9]+", POSTag::USER0, 0.0)?
+        .build(None, None)?;
+    let tokens = kiwi.analyze(text, 1, Match::new(), None, None)?.to_vec()?;
+
+    assert_eq!(tokens[0].0, "PROJ-1234", "{:?}", tokens);
+    assert_eq!(tokens[0].1.tag, POSTag::USER0);
+    assert_eq!(tokens[1].0, "이슈");
+
+    Ok(())
+}
+
+#[test]
+fn test_add_re_rule() -> anyhow::Result<()> {
+    let text = "밥 먹었어염";
+
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+    let without_rule = kiwi.analyze(text, 1, Match::new(), None, None)?.to_vec()?;
+
+    // "요"로 끝나는 종결 어미의 "염" 변이형을 추가함
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?
+        .add_re_rule(POSTag::EF, "요$", "염", 0.0)?
+        .build(None, None)?;
+    let with_rule = kiwi.analyze(text, 1, Match::new(), None, None)?.to_vec()?;
+
+    let (form, token) = with_rule.last().unwrap();
+
+    assert_eq!(token.tag, POSTag::EF, "{:?}", with_rule);
+    assert!(form.ends_with('염') || form.ends_with('요'), "{}", form);
+    assert_ne!(
+        without_rule.iter().map(|x| x.1.tag).collect::<Vec<_>>(),
+        with_rule.iter().map(|x| x.1.tag).collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_add_re_invalid_pattern() -> anyhow::Result<()> {
+    let err = KiwiBuilder::new(1, KiwiOptions::default())?
+        .add_re_word("PROJ-(", POSTag::USER0, 0.0)
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::Native(_)), "{:?}", err);
+
+    let err = KiwiBuilder::new(1, KiwiOptions::default())?
+        .add_re_rule(POSTag::EF, "[요", "염", 0.0)
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::Native(_)), "{:?}", err);
+
+    Ok(())
+}
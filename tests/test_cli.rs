@@ -0,0 +1,133 @@
+#![cfg(feature = "cli")]
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+const TEXT: &str = "밥을 먹었다.";
+
+fn kiwi(args: &[&str], stdin: &str) -> anyhow::Result<Output> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_kiwi"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // 입력을 다 쓴 뒤 닫아야 프로세스가 끝남
+    child.stdin.take().unwrap().write_all(stdin.as_bytes())?;
+
+    Ok(child.wait_with_output()?)
+}
+
+fn stdout(args: &[&str], stdin: &str) -> anyhow::Result<String> {
+    let output = kiwi(args, stdin)?;
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[test]
+fn test_cli_plain() -> anyhow::Result<()> {
+    let out = stdout(&[], TEXT)?;
+
+    assert_eq!(out, "밥/NNG 을/JKO 먹/VV 었/EP 다/EF ./SF\n");
+
+    // 빈 줄은 건너뜀
+    let out = stdout(&["--format", "plain"], &format!("{}\n\n{}\n", TEXT, TEXT))?;
+    assert_eq!(out.lines().count(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_cli_json() -> anyhow::Result<()> {
+    let out = stdout(&["--format", "json"], &format!("{}\n{}\n", TEXT, TEXT))?;
+
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+
+    let value: serde_json::Value = serde_json::from_str(lines[0])?;
+    assert_eq!(value["text"], TEXT);
+
+    let tokens = &value["candidates"][0]["tokens"];
+    assert_eq!(tokens[0]["form"], "밥");
+    assert_eq!(tokens[0]["token"]["tag"], "NNG");
+    assert_eq!(tokens.as_array().unwrap().len(), 6);
+
+    Ok(())
+}
+
+#[test]
+fn test_cli_conllu() -> anyhow::Result<()> {
+    let out = stdout(&["--format", "conllu"], TEXT)?;
+    let lines = out.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0], "# sent_id = 1");
+    assert_eq!(lines[1], format!("# text = {}", TEXT));
+    assert!(lines[2].starts_with("1-2\t밥을\t"), "{}", out);
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with("3\t먹\t먹다\tVERB\tVV\t")),
+        "{}",
+        out
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_cli_tsv() -> anyhow::Result<()> {
+    let out = stdout(&["--format", "tsv"], TEXT)?;
+
+    let rows = out
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split('\t').collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    assert_eq!(rows.len(), 6);
+    assert_eq!(rows[0], ["0", "밥", "NNG", "0", "3"]);
+
+    // 위치는 UTF-8 바이트 기준
+    assert_eq!(
+        rows[5].join("\t"),
+        format!("0\t.\tSF\t{}\t{}", TEXT.len() - 1, TEXT.len())
+    );
+
+    // 입력 한 줄의 결과는 빈 줄로 끝남
+    assert!(out.ends_with("\n\n"));
+
+    Ok(())
+}
+
+#[test]
+fn test_cli_inputs() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir().join(format!("rkiwi-cli-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir.join("input.txt");
+    std::fs::write(&path, format!("{}\n", TEXT))?;
+    let path_str = path.to_str().unwrap();
+
+    // 표준 입력을 여러 번 지정해도 멈추지 않고, 두 번째부터는 빈 입력이 됨
+    let out = stdout(&[path_str, "-", "-", path_str], &format!("{}\n", TEXT));
+    let missing = kiwi(&[path_str, dir.join("missing.txt").to_str().unwrap()], "");
+
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert_eq!(out?.lines().count(), 3);
+
+    let missing = missing?;
+    assert!(!missing.status.success());
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("missing.txt"));
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+#![cfg(feature = "config")]
+
+use std::path::PathBuf;
+
+use rkiwi::{DefaultTypoSet, Error, KiwiConfig, Match, POSTag};
+
+const TOML: &str = r#"
+num_threads = 1
+typo = "basic_typo_set"
+typo_cost_threshold = 2.5
+
+[options]
+integrate_allomorph = false
+
+[[words]]
+form = "키윗키윗"
+tag = "NNP"
+score = 1.0
+
+[[aliases]]
+form = "팅기"
+tag = "VV"
+origin = "튕기"
+
+[[pre_analyzed_words]]
+form = "사겼다"
+analyzed = [
+    { form = "사귀", tag = "VV", begin = 0, end = 2 },
+    { form = "었", tag = "EP", begin = 1, end = 2 },
+    { form = "다", tag = "EF", begin = 2, end = 3 },
+]
+score = -3.0
+
+[analyzer]
+space_tolerance = 2
+space_penalty = 5.0
+"#;
+
+/// 테스트마다 겹치지 않는 임시 폴더를 만들고, 해제될 때 지웁니다.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> std::io::Result<Self> {
+        let path = std::env::temp_dir().join(format!("rkiwi-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&path)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn test_config_from_toml() -> anyhow::Result<()> {
+    let config = KiwiConfig::from_toml_str(TOML)?;
+
+    assert_eq!(config.num_threads, Some(1));
+    assert_eq!(config.typo, Some(DefaultTypoSet::BasicTypoSet));
+    assert_eq!(config.typo_cost_threshold, Some(2.5));
+    assert_eq!(config.words[0].tag, POSTag::NNP);
+    assert_eq!(config.aliases[0].form, "팅기");
+    assert_eq!(config.aliases[0].origin, "튕기");
+    assert_eq!(config.pre_analyzed_words[0].analyzed.len(), 3);
+    assert_eq!(config.pre_analyzed_words[0].analyzed[1].begin, Some(1));
+    assert_eq!(config.analyzer.space_tolerance, Some(2));
+    assert_eq!(config.analyzer.cut_off_threshold, None);
+
+    // 직렬화한 결과를 다시 읽어도 같은 설정이어야 함
+    let json = KiwiConfig::from_json_str(&config.to_json_string()?)?;
+    assert_eq!(json.words, config.words);
+    assert_eq!(json.pre_analyzed_words, config.pre_analyzed_words);
+    assert_eq!(json.analyzer, config.analyzer);
+
+    let toml = KiwiConfig::from_toml_str(&config.to_toml_string()?)?;
+    assert_eq!(toml.aliases, config.aliases);
+
+    Ok(())
+}
+
+#[test]
+fn test_config_options() -> anyhow::Result<()> {
+    let config = KiwiConfig::from_toml_str("[options]\nmodel_type_sbg = true")?;
+    let options = serde_json::to_value(&config.options)?;
+
+    // 모델은 둘 중 하나만 선택됨
+    assert_eq!(options["model_type_sbg"], true);
+    assert_eq!(options["model_type_knlm"], false);
+    assert_eq!(options["load_default_dict"], true);
+
+    // 옵션 이름을 잘못 적은 경우 무시하지 않음
+    let res = KiwiConfig::from_toml_str("[options]\nmodel_typ_sbg = true");
+    assert!(matches!(res, Err(Error::Config(_))), "{:?}", res);
+
+    Ok(())
+}
+
+#[test]
+fn test_config_invalid() {
+    let res = KiwiConfig::from_toml_str("num_thread = 1");
+    assert!(matches!(res, Err(Error::Config(_))), "{:?}", res);
+
+    let res = KiwiConfig::from_json_str(r#"{"words": [{"form": "키위", "tag": "NOPE"}]}"#);
+    assert!(matches!(res, Err(Error::Config(_))), "{:?}", res);
+
+    let config = KiwiConfig {
+        typo_cost_threshold: Some(2.5),
+        ..Default::default()
+    };
+    let res = config.build();
+    assert!(matches!(res, Err(Error::Config(_))));
+}
+
+#[test]
+fn test_config_from_path() -> anyhow::Result<()> {
+    let dir = TempDir::new("config-from-path")?;
+
+    let path = dir.0.join("kiwi.json");
+    std::fs::write(
+        &path,
+        r#"{"dicts": ["user.dict"], "model_path": "/models"}"#,
+    )?;
+
+    let config = KiwiConfig::from_path(&path)?;
+
+    // 상대 경로는 설정 파일이 있는 폴더를 기준으로 함
+    assert_eq!(config.dicts, vec![dir.0.join("user.dict")]);
+    assert_eq!(config.model_path.as_deref(), Some("/models".as_ref()));
+
+    let res = KiwiConfig::from_path(dir.0.join("missing.toml"));
+    assert!(matches!(res, Err(Error::Config(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_config_build() -> anyhow::Result<()> {
+    let dir = TempDir::new("config-build")?;
+
+    std::fs::write(dir.0.join("user.dict"), "쿼카쿼카\tNNG\t0.0\n")?;
+
+    let path = dir.0.join("kiwi.toml");
+    std::fs::write(&path, format!("dicts = [\"user.dict\"]\n{}", TOML))?;
+
+    let kiwi = KiwiConfig::from_path(&path)?.build()?;
+
+    assert_eq!(kiwi.get_space_tolerance(), 2);
+    assert_eq!(kiwi.get_space_penalty(), 5.0);
+
+    let analyze = |text: &str| -> anyhow::Result<Vec<(String, POSTag)>> {
+        let tokens = kiwi.analyze(text, 1, Match::new(), None, None)?.to_vec()?;
+        Ok(tokens
+            .into_iter()
+            .map(|(form, token)| (form, token.tag))
+            .collect())
+    };
+
+    // 사용자 사전
+    let tokens = analyze("쿼카쿼카가 웃었다")?;
+    assert_eq!(
+        tokens[0],
+        ("쿼카쿼카".to_owned(), POSTag::NNG),
+        "{:?}",
+        tokens
+    );
+
+    // 사용자 형태소
+    let tokens = analyze("키윗키윗을 먹었다")?;
+    assert_eq!(
+        tokens[0],
+        ("키윗키윗".to_owned(), POSTag::NNP),
+        "{:?}",
+        tokens
+    );
+
+    // 변이형은 원본 형태소와 같이 분석되며, 형태는 새 형태소를 따름
+    let tokens = analyze("공을 팅겼다")?;
+    assert!(
+        tokens.contains(&("팅기".to_owned(), POSTag::VV)),
+        "{:?}",
+        tokens
+    );
+
+    // 기분석 형태소열
+    let tokens = analyze("사겼다")?;
+    assert_eq!(tokens[0], ("사귀".to_owned(), POSTag::VV), "{:?}", tokens);
+
+    Ok(())
+}
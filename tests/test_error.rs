@@ -0,0 +1,76 @@
+use rkiwi::{Error, KiwiBuilder, KiwiOptions, POSTag};
+
+#[test]
+fn test_model_load_error() {
+    let err = KiwiBuilder::with_model_path("./not-exists", 1, KiwiOptions::default())
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::ModelLoad { .. }), "{:?}", err);
+    assert!(!err.message().is_empty());
+}
+
+#[test]
+fn test_invalid_tag_error() {
+    let err = "NOPE".parse::<POSTag>().unwrap_err();
+
+    assert!(
+        matches!(err, Error::InvalidTag(ref tag) if tag == "NOPE"),
+        "{:?}",
+        err
+    );
+}
+
+#[test]
+fn test_dict_parse_error() -> anyhow::Result<()> {
+    let err = KiwiBuilder::new(1, KiwiOptions::default())?
+        .load_dict("./not-exists.dict")
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::DictParse { .. }), "{:?}", err);
+
+    Ok(())
+}
+
+#[test]
+fn test_out_of_range_error() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let err = kiwi.morpheme(u32::MAX).unwrap_err();
+
+    assert!(matches!(err, Error::OutOfRange(_)), "{:?}", err);
+
+    Ok(())
+}
+
+#[test]
+fn test_nul_in_text() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "만둣국\0먹고 싶다.";
+
+    let analyzed = kiwi.analyze(text, 1, rkiwi::Match::new(), None, None)?;
+
+    for item in analyzed.iter_t() {
+        let token = item?;
+
+        assert!(!text[token.byte_begin..token.byte_end].contains('\0'));
+    }
+
+    assert_eq!(analyzed.surface(0, 0)?, Some("만둣국"));
+
+    Ok(())
+}
+
+#[test]
+fn test_nul_in_word() -> anyhow::Result<()> {
+    let err = KiwiBuilder::new(1, KiwiOptions::default())?
+        .add_word("벨리\0타", POSTag::NNP, 0.0)
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::InvalidInput(_)), "{:?}", err);
+
+    Ok(())
+}
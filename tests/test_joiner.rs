@@ -0,0 +1,83 @@
+use rkiwi::{Joiner, KiwiBuilder, KiwiOptions, POSTag};
+use widestring::U16String;
+
+#[test]
+fn test_join() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let morphs = [("먹", POSTag::VV), ("었", POSTag::EP), ("다", POSTag::EF)];
+
+    assert_eq!(kiwi.join(morphs)?, "먹었다");
+
+    // 음운론적 이형태는 앞 형태소에 맞춰 선택됨
+    let morphs = [("보", POSTag::VV), ("었", POSTag::EP), ("다", POSTag::EF)];
+
+    assert_eq!(kiwi.join(morphs)?, "봤다");
+
+    assert_eq!(kiwi.join([])?, "");
+
+    Ok(())
+}
+
+#[test]
+fn test_joiner_regularity() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    // 추론하지 않으면 품사 태그의 불규칙 활용 여부를 따름
+    let join = |tag: POSTag| -> rkiwi::Result<String> {
+        let joiner = Joiner::new(&kiwi, false)?;
+        joiner.add("묻", tag, false)?;
+        joiner.add("어요", POSTag::EF, false)?;
+        joiner.get()
+    };
+
+    assert_eq!(join(POSTag::VV)?, "묻어요");
+    assert_eq!(join(POSTag::VVI)?, "물어요");
+
+    Ok(())
+}
+
+#[test]
+fn test_joiner() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let joiner = Joiner::new(&kiwi, false)?;
+
+    joiner.add("먹", POSTag::VV, true)?;
+    joiner.add("었", POSTag::EP, true)?;
+    joiner.add("다", POSTag::EF, true)?;
+
+    assert_eq!(joiner.get()?, "먹었다");
+    assert_eq!(joiner.get_w()?, U16String::from_str("먹었다"));
+
+    Ok(())
+}
+
+#[test]
+fn test_joiner_concurrent_setter() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    // 결합하는 동안 다른 스레드에서 옵션을 바꿔도 안전해야 함
+    let setter = {
+        let kiwi = kiwi.clone();
+        std::thread::spawn(move || {
+            for i in 0..200 {
+                kiwi.set_space_penalty(7.0 + (i % 2) as f32);
+            }
+        })
+    };
+
+    for _ in 0..200 {
+        let joiner = Joiner::new(&kiwi, true)?;
+
+        joiner.add("먹", POSTag::VV, true)?;
+        joiner.add("었", POSTag::EP, true)?;
+        joiner.add("다", POSTag::EF, true)?;
+
+        assert_eq!(joiner.get()?, "먹었다");
+    }
+
+    setter.join().unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use rkiwi::{KiwiBuilder, KiwiOptions, Match, MorphemeSet, POSTag, Stopwords};
+
+#[test]
+fn test_extract_nouns() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "형태소 분석기로 형태소를 분석합니다.";
+    let nouns = kiwi.extract_nouns(text, Match::new(), false, None)?;
+
+    assert_eq!(nouns[0].form, "형태소");
+    assert_eq!(nouns[0].tag, POSTag::NNG);
+    assert_eq!(nouns[0].count, 2);
+
+    for (begin, end) in &nouns[0].spans {
+        assert_eq!(&text[*begin..*end], "형태소");
+    }
+
+    assert!(nouns.iter().all(|noun| noun.tag != POSTag::JKB));
+
+    Ok(())
+}
+
+#[test]
+fn test_extract_nouns_merge_compound() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "형태소분석기를 만들었다.";
+    let nouns = kiwi.extract_nouns(text, Match::new(), true, None)?;
+
+    assert_eq!(nouns[0].form, "형태소분석기");
+    assert_eq!(nouns[0].spans, vec![(0, "형태소분석기".len())]);
+
+    Ok(())
+}
+
+#[test]
+fn test_extract_nouns_match_and_stopwords() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "#맛집 추천 https://example.com 추천";
+
+    let nouns = kiwi.extract_nouns(text, Match::new(), false, None)?;
+    assert!(nouns.iter().all(|noun| noun.tag != POSTag::W_HASHTAG));
+
+    let nouns = kiwi.extract_nouns(text, Match::new().hashtag(true).url(true), false, None)?;
+    assert!(nouns.iter().any(|noun| noun.tag == POSTag::W_HASHTAG));
+    assert!(nouns.iter().any(|noun| noun.tag == POSTag::W_URL));
+
+    let stopwords = Stopwords::from_iter([("추천", POSTag::NNG)]);
+
+    let nouns = kiwi.extract_nouns(text, Match::new(), false, &stopwords)?;
+    assert!(nouns.iter().all(|noun| noun.form != "추천"));
+
+    // 사전에 없는 형태소는 MorphemeSet에 추가되지 않음
+    let morphemes = MorphemeSet::new(&kiwi);
+    assert!(morphemes.add("추천", POSTag::NNG)?);
+    assert!(morphemes.contains("추천", POSTag::NNG));
+    assert!(!morphemes.add("뀨꺄뀨", POSTag::NNG)?);
+    assert!(!morphemes.contains("뀨꺄뀨", POSTag::NNG));
+
+    let keywords = kiwi.keywords(text, 1, Match::new().hashtag(true), false, None)?;
+    assert_eq!(keywords.len(), 1);
+    assert_eq!(keywords[0].form, "추천");
+    assert_eq!(keywords[0].count, 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_stopwords_outside_dictionary() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "#맛집 Kiwi 추천 #맛집 Kiwi";
+    let match_options = Match::new().hashtag(true);
+
+    let nouns = kiwi.extract_nouns(text, match_options, false, None)?;
+    assert!(nouns
+        .iter()
+        .any(|noun| noun.form == "Kiwi" && noun.tag == POSTag::SL));
+    assert!(nouns
+        .iter()
+        .any(|noun| noun.form == "#맛집" && noun.tag == POSTag::W_HASHTAG));
+
+    // 사전에 없는 SL, 해시태그 형태소도 불용어로 제외됨
+    let mut stopwords = Stopwords::new();
+    stopwords.add("Kiwi", POSTag::SL);
+    stopwords.add("#맛집", POSTag::W_HASHTAG);
+
+    let nouns = kiwi.extract_nouns(text, match_options, false, &stopwords)?;
+    assert!(
+        nouns
+            .iter()
+            .all(|noun| noun.form != "Kiwi" && noun.form != "#맛집"),
+        "{:?}",
+        nouns
+    );
+    assert!(nouns.iter().any(|noun| noun.form == "추천"));
+
+    let keywords = kiwi.keywords(text, 1, match_options, false, &stopwords)?;
+    assert_eq!(keywords[0].form, "추천");
+
+    Ok(())
+}
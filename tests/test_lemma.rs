@@ -0,0 +1,50 @@
+use rkiwi::{KiwiBuilder, KiwiOptions, Lemmatizer, Match, POSTag};
+
+#[test]
+fn test_lemmatize() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let analyzed = kiwi.analyze("먹었습니다", 1, Match::new(), None, None)?;
+    let lemmas = Lemmatizer::new().lemmatize_analyzed(&analyzed, 0)?.unwrap();
+
+    assert_eq!(lemmas[0].form, "먹다");
+    assert_eq!(lemmas[0].tag, POSTag::VV);
+    assert_eq!(lemmas.len(), analyzed.word_num(0).unwrap());
+
+    let analyzed = kiwi.analyze("고마움", 1, Match::new().split_complex(true), None, None)?;
+    let lemmas = Lemmatizer::new().lemmatize_analyzed(&analyzed, 0)?.unwrap();
+
+    assert_eq!(lemmas[0].form, "고맙다");
+    assert_eq!(lemmas[0].tag, POSTag::VAI);
+
+    assert!(Lemmatizer::new()
+        .lemmatize_analyzed(&analyzed, analyzed.size())?
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_lemmatize_merge_derivational() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "사랑했다";
+    let analyzed = kiwi.analyze(text, 1, Match::new(), None, None)?;
+    let tokens = analyzed.to_vec()?;
+
+    let lemmas = Lemmatizer::new().lemmatize(&tokens);
+
+    assert_eq!(lemmas[0].form, "사랑");
+    assert_eq!(lemmas[1].form, "하다");
+
+    let lemmas = Lemmatizer::new()
+        .merge_derivational(true)
+        .lemmatize(&tokens);
+
+    assert_eq!(lemmas[0].form, "사랑하다");
+    assert_eq!(lemmas[0].tag, POSTag::VV);
+    assert_eq!(lemmas[0].morphs, 0..2);
+    assert_eq!(&text[lemmas[0].byte_begin..lemmas[0].byte_end], "사랑했");
+
+    Ok(())
+}
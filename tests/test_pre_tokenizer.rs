@@ -0,0 +1,47 @@
+#![cfg(feature = "tokenizers")]
+
+use rkiwi::{KiwiBuilder, KiwiOptions, KiwiPreTokenizer};
+use tokenizers::{OffsetReferential, OffsetType, PreTokenizedString, PreTokenizer};
+
+#[test]
+fn test_pre_tokenizer() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "오늘은 🍕를 먹었다.";
+
+    let pre_tokenizer = KiwiPreTokenizer::new(kiwi.clone());
+    let mut pretokenized = PreTokenizedString::from(text);
+    pre_tokenizer
+        .pre_tokenize(&mut pretokenized)
+        .map_err(|err| anyhow::anyhow!(err))?;
+
+    let splits = pretokenized.get_splits(OffsetReferential::Original, OffsetType::Byte);
+
+    assert_eq!(splits[0].0, "오늘");
+    for (split, (begin, end), _) in &splits {
+        assert_eq!(&text[*begin..*end], *split);
+    }
+    assert!(splits.iter().all(|(split, _, _)| !split.contains(' ')));
+
+    // 태그는 모델 입력을 바꾸지 않고 따로 얻을 수 있음
+    let spans = pre_tokenizer.tagged_spans(text)?;
+
+    assert_eq!(spans.len(), splits.len());
+    for ((begin, end, _), (_, offsets, _)) in spans.iter().zip(&splits) {
+        assert_eq!((*begin, *end), *offsets);
+    }
+    assert_eq!(spans[0].2, "NNG");
+
+    let pre_tokenizer = KiwiPreTokenizer::new(kiwi).append_tags_to_input(true);
+    let mut pretokenized = PreTokenizedString::from(text);
+    pre_tokenizer
+        .pre_tokenize(&mut pretokenized)
+        .map_err(|err| anyhow::anyhow!(err))?;
+
+    let splits = pretokenized.get_splits(OffsetReferential::Original, OffsetType::Byte);
+
+    assert_eq!(splits[0].0, "오늘/NNG");
+    assert_eq!(splits[0].1, (0, "오늘".len()));
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "serde")]
+
+use rkiwi::{KiwiBuilder, KiwiOptions, Match, POSTag};
+
+#[test]
+fn test_pos_tag_serde() -> anyhow::Result<()> {
+    assert_eq!(serde_json::to_string(&POSTag::NNG)?, r#""NNG""#);
+    assert_eq!(serde_json::to_string(&POSTag::VVI)?, r#""VV-I""#);
+
+    assert_eq!(serde_json::from_str::<POSTag>(r#""W_URL""#)?, POSTag::W_URL);
+    assert_eq!(serde_json::from_str::<POSTag>(r#""VA-I""#)?, POSTag::VAI);
+    assert!(serde_json::from_str::<POSTag>(r#""NOPE""#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_match_serde() -> anyhow::Result<()> {
+    let match_options = Match::new().url(true).split_complex(true);
+
+    let json = serde_json::to_string(&match_options)?;
+
+    assert_eq!(json, r#"["url","split_complex"]"#);
+
+    let de = serde_json::from_str::<Match>(&json)?;
+
+    assert_eq!(de, match_options);
+    assert!(serde_json::from_str::<Match>(r#"["nope"]"#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_analyzed_serde() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let result = kiwi
+        .analyze("만둣국 먹고 싶다.", 1, Match::new(), None, None)?
+        .to_result()?;
+
+    let json = serde_json::to_string(&result)?;
+
+    assert_eq!(
+        serde_json::from_str::<rkiwi::AnalysisResult>(&json)?,
+        result
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_kiwi_options_serde() -> anyhow::Result<()> {
+    let options = serde_json::from_str::<KiwiOptions>(r#"{ "load_typo_dict": false }"#)?;
+
+    let json = serde_json::to_value(&options)?;
+
+    assert_eq!(json["load_typo_dict"], false);
+    assert_eq!(json["load_default_dict"], true);
+
+    Ok(())
+}
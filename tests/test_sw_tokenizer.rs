@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use rkiwi::{Error, Kiwi, KiwiBuilder, KiwiOptions, SwTokenizer};
+
+fn tokenizer_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Kiwi/test/written.tokenizer.json")
+}
+
+fn kiwi() -> anyhow::Result<Kiwi> {
+    Ok(KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?)
+}
+
+#[test]
+fn test_sw_tokenizer_round_trip() -> anyhow::Result<()> {
+    let kiwi = kiwi()?;
+    let tokenizer = SwTokenizer::new(tokenizer_path(), &kiwi)?;
+
+    for text in [
+        "안녕하세요 저는 바보입니다.",
+        "만둣국 먹고 싶다.",
+        "Kiwi는 한국어 형태소 분석기입니다.",
+    ] {
+        let token_ids = tokenizer.encode(text)?;
+
+        assert!(!token_ids.is_empty(), "{}", text);
+        assert_eq!(tokenizer.decode(&token_ids)?, text);
+    }
+
+    assert!(tokenizer.encode("")?.is_empty());
+    assert_eq!(tokenizer.decode(&[])?, "");
+
+    Ok(())
+}
+
+#[test]
+fn test_sw_tokenizer_offsets() -> anyhow::Result<()> {
+    let kiwi = kiwi()?;
+    let tokenizer = SwTokenizer::new(tokenizer_path(), &kiwi)?;
+
+    let text = "안녕하세요 만둣국 먹고 싶다.";
+
+    let (token_ids, offsets) = tokenizer.encode_with_offsets(text)?;
+
+    assert_eq!(token_ids, tokenizer.encode(text)?);
+    assert_eq!(token_ids.len(), offsets.len());
+
+    let mut prev_begin = 0;
+
+    for &(begin, end) in &offsets {
+        // UTF-8 바이트 위치이므로 글자 경계에 맞아야 함
+        assert!(begin <= end && end <= text.len(), "{:?}", (begin, end));
+        assert!(text.is_char_boundary(begin) && text.is_char_boundary(end));
+        assert!(begin >= prev_begin, "{:?}", offsets);
+
+        prev_begin = begin;
+    }
+
+    let (begin, end) = offsets[0];
+    assert_eq!(begin, 0);
+    assert!(!text[begin..end].is_empty() && "안녕하세요".starts_with(&text[begin..end]));
+
+    // 마지막 토큰은 마침표이며, UTF-16 위치였다면 문자열 길이보다 훨씬 앞에서 끝남
+    let (begin, end) = offsets[offsets.len() - 1];
+    assert_eq!(&text[begin..end], ".");
+    assert_eq!(end, text.len());
+
+    Ok(())
+}
+
+#[test]
+fn test_sw_tokenizer_not_found() -> anyhow::Result<()> {
+    let kiwi = kiwi()?;
+
+    let err = SwTokenizer::new("not_found.tokenizer.json", &kiwi)
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, Error::ModelLoad { .. }), "{:?}", err);
+
+    Ok(())
+}
+
+#[test]
+fn test_sw_tokenizer_concurrent_setter() -> anyhow::Result<()> {
+    let kiwi = kiwi()?;
+    let tokenizer = SwTokenizer::new(tokenizer_path(), &kiwi)?;
+
+    let text = "안녕하세요 만둣국 먹고 싶다.";
+    let expected = tokenizer.encode(text)?;
+
+    // 토크나이저가 분석하는 동안 다른 스레드에서 옵션을 바꿔도 안전해야 함
+    let setter = {
+        let kiwi = kiwi.clone();
+        std::thread::spawn(move || {
+            for i in 0..200 {
+                kiwi.set_space_penalty(7.0 + (i % 2) as f32);
+            }
+        })
+    };
+
+    for _ in 0..200 {
+        assert_eq!(tokenizer.decode(&tokenizer.encode(text)?)?, text);
+    }
+
+    setter.join().unwrap();
+
+    assert_eq!(tokenizer.encode(text)?, expected);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use rkiwi::{Error, POSTag, TagSet};
+
+#[test]
+fn test_pos_tag_category() {
+    assert_eq!(POSTag::VVI.base(), POSTag::VV);
+    assert_eq!(POSTag::NNG.base(), POSTag::NNG);
+    assert!(POSTag::VVI.is_irregular());
+    assert!(!POSTag::VV.is_irregular());
+
+    assert!(POSTag::NNB.is_nominal());
+    assert!(POSTag::VAI.is_predicate());
+    assert!(!POSTag::XSV.is_predicate());
+    assert!(POSTag::XSAI.is_affix());
+    assert!(POSTag::JX.is_josa());
+    assert!(POSTag::ETM.is_eomi());
+    assert!(POSTag::SSO.is_symbol());
+    assert!(POSTag::SL.is_foreign());
+    assert!(POSTag::W_HASHTAG.is_web());
+    assert!(POSTag::USER2.is_user());
+    assert!(!POSTag::EP.is_josa());
+}
+
+#[test]
+fn test_tag_set() {
+    let mut set = TagSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(POSTag::NNG));
+    assert!(!set.insert(POSTag::NNG));
+    assert!(set.insert(POSTag::VVI));
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(POSTag::VVI));
+    assert!(!set.contains(POSTag::VV));
+    assert!(set.remove(POSTag::VVI));
+    assert!(!set.remove(POSTag::VVI));
+
+    let a = TagSet::from([POSTag::NNG, POSTag::NNP]);
+    let b = TagSet::from([POSTag::NNP, POSTag::VV]);
+
+    assert_eq!(a | b, TagSet::from([POSTag::NNG, POSTag::NNP, POSTag::VV]));
+    assert_eq!(a & b, TagSet::from(POSTag::NNP));
+    assert_eq!(a - b, TagSet::from(POSTag::NNG));
+    assert_eq!(a.union(&b), a | b);
+    assert!(!(!a).contains(POSTag::NNG));
+    assert_eq!((!a).len(), POSTag::ALL.len() - 2);
+
+    assert_eq!(
+        (a | b).iter().collect::<Vec<_>>(),
+        vec![POSTag::NNG, POSTag::NNP, POSTag::VV]
+    );
+}
+
+#[test]
+fn test_tag_set_from_str() -> anyhow::Result<()> {
+    let set: TagSet = "NN*, VV*".parse()?;
+    assert_eq!(
+        set,
+        TagSet::from([
+            POSTag::NNG,
+            POSTag::NNP,
+            POSTag::NNB,
+            POSTag::VV,
+            POSTag::VVI
+        ])
+    );
+    assert_eq!(set.to_string(), "NNG,NNP,NNB,VV,VV-I");
+    assert_eq!(set.to_string().parse::<TagSet>()?, set);
+
+    let set: TagSet = "V*".parse()?;
+    assert!(set.contains(POSTag::VCP));
+    assert!(set.contains(POSTag::VXI));
+
+    assert!(matches!("NNG,XX*".parse::<TagSet>(), Err(Error::InvalidTag(tag)) if tag == "XX*"));
+    assert!(matches!(
+        "NNG,FOO".parse::<TagSet>(),
+        Err(Error::InvalidTag(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "tantivy")]
+
+use rkiwi::{KiwiBuilder, KiwiOptions, KiwiTokenizer, TokenText};
+use tantivy_tokenizer_api::{Token, TokenStream, Tokenizer};
+
+fn tokens(tokenizer: &mut KiwiTokenizer, text: &str) -> Vec<Token> {
+    let mut stream = tokenizer.token_stream(text);
+    let mut tokens = Vec::new();
+    stream.process(&mut |token| tokens.push(token.clone()));
+    tokens
+}
+
+#[test]
+fn test_tantivy_tokenizer() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let text = "밥을 먹었다.";
+    let mut tokenizer = KiwiTokenizer::new(kiwi.clone());
+    let res = tokens(&mut tokenizer, text);
+
+    let texts: Vec<_> = res.iter().map(|token| token.text.as_str()).collect();
+    assert_eq!(texts, vec!["밥", "먹"]);
+
+    assert_eq!(res[0].position, 0);
+    assert_eq!(res[1].position, 1);
+    for token in &res {
+        assert_eq!(&text[token.offset_from..token.offset_to], token.text);
+    }
+
+    let mut tokenizer = KiwiTokenizer::new(kiwi.clone()).text(TokenText::Lemma);
+    let res = tokens(&mut tokenizer, text);
+    assert_eq!(res[1].text, "먹다");
+
+    let mut tokenizer = KiwiTokenizer::new(kiwi).tags("EF".parse()?);
+    let res = tokens(&mut tokenizer, text);
+    assert_eq!(res.len(), 1);
+    assert_eq!(res[0].text, "다");
+
+    Ok(())
+}
+
+#[test]
+fn test_tantivy_tokenizer_compound_nouns() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let mut tokenizer = KiwiTokenizer::new(kiwi)
+        .tags("NN*".parse()?)
+        .compound_nouns(true);
+    let res = tokens(&mut tokenizer, "형태소분석기");
+
+    // 복합 명사가 구성 명사들보다 먼저 나옴
+    assert!(res.len() >= 3);
+    assert_eq!(
+        res[0].text,
+        res[1..]
+            .iter()
+            .map(|token| token.text.as_str())
+            .collect::<String>()
+    );
+    assert_eq!(res[0].offset_from, res[1].offset_from);
+    assert_eq!(res[0].offset_to, res[res.len() - 1].offset_to);
+    assert!(res.iter().all(|token| token.position == 0));
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use rkiwi::{KiwiBuilder, KiwiOptions, Match, POSTag, UPos};
+
+#[test]
+fn test_upos() -> anyhow::Result<()> {
+    assert_eq!(POSTag::NNG.to_upos(), UPos::NOUN);
+    assert_eq!(POSTag::VVI.to_upos(), UPos::VERB);
+    assert_eq!(POSTag::JKS.to_upos(), UPos::ADP);
+    assert_eq!(POSTag::EC.to_upos(), UPos::SCONJ);
+    assert_eq!(POSTag::W_EMOJI.to_upos(), UPos::SYM);
+    assert_eq!(POSTag::USER0.to_upos(), UPos::X);
+    assert_eq!(POSTag::VCN.ud_feats(), Some("Polarity=Neg"));
+    assert_eq!(POSTag::NNG.ud_feats(), None);
+
+    assert_eq!("PROPN".parse::<UPos>()?, UPos::PROPN);
+    assert!("propn".parse::<UPos>().is_err());
+
+    for tag in [
+        POSTag::NNG,
+        POSTag::NNP,
+        POSTag::VV,
+        POSTag::VA,
+        POSTag::MAG,
+    ] {
+        assert_eq!(POSTag::from_upos(tag.to_upos()), Some(tag));
+    }
+    assert_eq!(POSTag::from_upos(UPos::ADP), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_sejong() -> anyhow::Result<()> {
+    assert_eq!(POSTag::VVI.to_sejong(), "VV");
+    assert_eq!(POSTag::SSO.to_sejong(), "SS");
+    assert_eq!(POSTag::W_URL.to_sejong(), "SL");
+    assert_eq!(POSTag::Z_SIOT.to_sejong(), "NA");
+    assert_eq!(POSTag::USER3.to_sejong(), "NA");
+
+    for tag in POSTag::ALL {
+        POSTag::from_sejong(tag.to_sejong())?;
+    }
+
+    assert_eq!(POSTag::from_sejong("JKB")?, POSTag::JKB);
+    assert_eq!(POSTag::from_sejong("NF")?, POSTag::UNK);
+    assert!(POSTag::from_sejong("W_URL").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_to_conllu() -> anyhow::Result<()> {
+    let kiwi = KiwiBuilder::new(1, KiwiOptions::default())?.build(None, None)?;
+
+    let analyzed = kiwi.analyze("밥을 먹었다. 맛있다!", 1, Match::new(), None, None)?;
+    let conllu = analyzed.to_conllu(0)?.unwrap();
+
+    let mut lines = conllu.lines();
+    assert_eq!(lines.next(), Some("# sent_id = 1"));
+    assert_eq!(lines.next(), Some("# text = 밥을 먹었다."));
+    assert_eq!(lines.next(), Some("1-2\t밥을\t_\t_\t_\t_\t_\t_\t_\t_"));
+    assert_eq!(lines.next(), Some("1\t밥\t밥\tNOUN\tNNG\t_\t_\t_\t_\t_"));
+    assert!(conllu.contains("\t먹\t먹다\tVERB\tVV\t"));
+    assert!(conllu.contains("# sent_id = 2"));
+
+    assert!(conllu
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .all(|line| line.split('\t').count() == 10));
+
+    assert!(analyzed.to_conllu(analyzed.size())?.is_none());
+
+    Ok(())
+}